@@ -1,6 +1,27 @@
-use crate::solana::{Network, SolanaClient};
+use crate::solana::{Commitment, Network, SolanaClient};
+use crate::ui::Theme;
+pub use crate::utils::format::BalanceUnit;
+use crate::utils::validators;
+pub use crate::utils::validators::InputType;
+use ratatui::layout::Rect;
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
-use std::str::FromStr;
+use std::cell::Cell;
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const STATUS_MESSAGE_TTL: Duration = Duration::from_secs(4);
+/// Oldest views are dropped beyond this many saved on the navigation stack.
+const MAX_NAV_DEPTH: usize = 50;
+/// Pre-filled custom endpoint: a local `solana-test-validator`.
+const DEFAULT_CUSTOM_RPC_URL: &str = "http://localhost:8899";
+/// Recent queries remembered for the input screen's suggestions.
+pub const MAX_RECENT_QUERIES: usize = 10;
+/// Matches the RPC client's own default.
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(30);
+/// How often the account view re-fetches itself while auto-refresh is on.
+pub const AUTO_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Screen {
@@ -9,6 +30,8 @@ pub enum Screen {
     Loading,
     Transaction,
     Account,
+    /// Results of a multi-signature lookup, one row per signature
+    Batch,
     Error(String),
 }
 
@@ -16,32 +39,185 @@ pub enum Screen {
 pub struct App {
     pub screen: Screen,
     pub input: String,
+    /// Byte offset of the cursor in `input`, always on a char boundary
     pub input_cursor: usize,
     pub selected_network: Network,
+    /// URL typed for the Custom network, kept while other networks are selected
+    pub custom_rpc_url: String,
+    pub commitment: Commitment,
+    pub rpc_timeout: Duration,
     pub error_message: Option<String>,
-    #[allow(dead_code)]
-    pub solana_client: Option<SolanaClient>,
+    /// Client of the last query, reused while the network and commitment stay the same
+    pub solana_client: Option<Arc<SolanaClient>>,
     pub transaction_data: Option<solana::TransactionData>,
     pub account_data: Option<solana::AccountData>,
+    /// Transactions of the last multi-signature lookup, in the order they were entered
+    pub batch: Vec<BatchEntry>,
+    pub batch_selected: usize,
     pub txn_scroll: usize,
-    pub account_scroll: usize,
+    /// Largest useful `txn_scroll` for the current tab, recorded by the last draw
+    pub txn_scroll_max: Cell<usize>,
+    /// Columns the Instructions and Logs tabs are shifted left by; lines stop wrapping
+    /// while this is non-zero
+    pub h_scroll: usize,
+    /// Largest useful `h_scroll` for the current tab, recorded by the last draw
+    pub h_scroll_max: Cell<usize>,
+    /// Screen area of each tab title, in `TransactionTab::ALL` order, recorded by the last
+    /// draw so mouse clicks can be mapped to tabs
+    pub tab_areas: Cell<[Rect; TransactionTab::ALL.len()]>,
+    pub history_selected: usize,
+    pub token_scroll: usize,
+    /// Largest useful `token_scroll`, recorded by the last draw
+    pub token_scroll_max: Cell<usize>,
+    pub account_pane: AccountPane,
+    /// Replace the token and history panes with a hex dump of the account data
+    pub show_hex_dump: bool,
+    /// First hex dump row shown
+    pub hex_scroll: usize,
+    /// Largest useful `hex_scroll`, recorded by the last draw
+    pub hex_scroll_max: Cell<usize>,
+    pub balance_unit: BalanceUnit,
+    pub theme: Theme,
+    /// How instruction data bytes are shown on the Instructions tab
+    pub data_encoding: DataEncoding,
+    /// Collapse the transaction view into its one-line summary
+    pub compact_view: bool,
     pub transaction_tab: TransactionTab,
+    /// Tab and Shift+Tab pass over tabs with nothing to show
+    pub skip_empty_tabs: bool,
+    pub accounts_selected: usize,
+    pub accounts_expanded: bool,
+    /// Minimum absolute balance change, in lamports, for an account to be listed
+    pub balance_filter: Option<u64>,
+    /// Threshold being typed in the Accounts tab, while the filter prompt is open
+    pub balance_filter_input: Option<String>,
+    /// Signature or address being typed in the `/` bar on a result screen
+    pub quick_search: Option<String>,
+    /// Previous result views, most recent last, restored with Backspace
+    pub nav_stack: Vec<NavEntry>,
+    /// Recently fetched signatures and addresses, most recent first
+    pub recent_queries: Vec<String>,
+    /// Suggestion currently copied into the input, while browsing with the arrow keys
+    pub recent_selected: Option<usize>,
+    pub scroll_step: usize,
+    pub page_scroll_step: usize,
+    pub show_legend: bool,
+    pub show_about: bool,
+    pub max_supported_transaction_version: Option<u8>,
+    pub skip_network_selection: bool,
+    /// Fetch fees for account history rows, at one extra request per row
+    pub history_fees: bool,
+    pub status_message: Option<StatusMessage>,
+    /// Most recent file written by an export, for opening in the editor.
+    pub last_export: Option<PathBuf>,
+    /// File the main loop should open in `$EDITOR` before the next draw.
+    pub pending_editor: Option<PathBuf>,
+    /// Result channel of the fetch running in the background while `Screen::Loading`
+    pub pending_fetch: Option<Receiver<FetchResult>>,
+    /// Result channel of an in-flight auto-refresh, kept apart so it never holds up a
+    /// history page the user asked for
+    pub pending_refresh: Option<Receiver<anyhow::Result<solana::AccountData>>>,
+    /// Spinner frame on the loading screen, advanced once per event-loop iteration
+    pub loading_tick: usize,
+    pub loading_started: Option<Instant>,
+    /// Re-fetch the account view every `AUTO_REFRESH_INTERVAL` while it's on screen
+    pub auto_refresh: bool,
+    /// When auto-refresh last brought back a result; `None` makes the next refresh due
+    /// straight away
+    pub last_refresh: Option<Instant>,
+    /// Ask for a second Esc before quitting from a result screen
+    pub confirm_quit: bool,
+    /// When Esc last asked to quit, while waiting for the confirming press
+    pub quit_requested: Option<Instant>,
+}
+
+/// Outcome of a background fetch, sent back to the event loop.
+#[derive(Debug)]
+pub enum FetchResult {
+    Transaction(anyhow::Result<solana::TransactionData>),
+    Account(anyhow::Result<solana::AccountData>),
+    Batch(Vec<BatchEntry>),
+    /// Next page of history for the account view showing `pubkey`
+    History {
+        pubkey: Pubkey,
+        result: anyhow::Result<Vec<solana::TransactionSummary>>,
+    },
+}
+
+/// One signature of a multi-signature lookup.
+#[derive(Debug, Clone)]
+pub struct BatchEntry {
+    pub signature: String,
+    /// The fetched transaction, or why it couldn't be fetched
+    pub result: Result<solana::TransactionData, String>,
+}
+
+/// A result view saved on the navigation stack.
+#[derive(Debug, Clone)]
+pub struct NavEntry {
+    screen: Screen,
+    input: String,
+    transaction_data: Option<solana::TransactionData>,
+    account_data: Option<solana::AccountData>,
+    transaction_tab: TransactionTab,
+    txn_scroll: usize,
+    accounts_selected: usize,
+    history_selected: usize,
+    token_scroll: usize,
+    account_pane: AccountPane,
+    show_hex_dump: bool,
+    hex_scroll: usize,
 }
 
+#[derive(Debug, Clone)]
+pub struct StatusMessage {
+    pub text: String,
+    pub is_error: bool,
+    created_at: Instant,
+}
+
+/// Message of the last panic on a fetch thread, recorded by the panic hook so the hung-up
+/// fetch can be reported with its cause instead of printed over the TUI.
+pub static WORKER_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+const DEFAULT_SCROLL_STEP: usize = 1;
+const DEFAULT_PAGE_SCROLL_STEP: usize = 10;
+const MAX_SCROLL_STEP: usize = 20;
+/// How much `>` / `<` change the PageUp/PageDown step by, and the smallest step they allow.
+const PAGE_SCROLL_STEP_INCREMENT: usize = 5;
+const MAX_PAGE_SCROLL_STEP: usize = 50;
+/// Columns a single Shift+←/→ press scrolls sideways.
+const H_SCROLL_STEP: usize = 8;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TransactionTab {
     Overview,
+    Fees,
     Accounts,
+    BalanceChanges,
     Instructions,
     TokenTransfers,
     Logs,
 }
 
 impl TransactionTab {
+    /// Every tab, in display order.
+    pub const ALL: [TransactionTab; 7] = [
+        Self::Overview,
+        Self::Fees,
+        Self::Accounts,
+        Self::BalanceChanges,
+        Self::Instructions,
+        Self::TokenTransfers,
+        Self::Logs,
+    ];
+
     pub fn next(&self) -> Self {
         match self {
-            Self::Overview => Self::Accounts,
-            Self::Accounts => Self::Instructions,
+            Self::Overview => Self::Fees,
+            Self::Fees => Self::Accounts,
+            Self::Accounts => Self::BalanceChanges,
+            Self::BalanceChanges => Self::Instructions,
             Self::Instructions => Self::TokenTransfers,
             Self::TokenTransfers => Self::Logs,
             Self::Logs => Self::Overview,
@@ -51,8 +227,10 @@ impl TransactionTab {
     pub fn prev(&self) -> Self {
         match self {
             Self::Overview => Self::Logs,
-            Self::Accounts => Self::Overview,
-            Self::Instructions => Self::Accounts,
+            Self::Fees => Self::Overview,
+            Self::Accounts => Self::Fees,
+            Self::BalanceChanges => Self::Accounts,
+            Self::Instructions => Self::BalanceChanges,
             Self::TokenTransfers => Self::Instructions,
             Self::Logs => Self::TokenTransfers,
         }
@@ -61,12 +239,89 @@ impl TransactionTab {
     pub fn title(&self) -> &'static str {
         match self {
             Self::Overview => "Overview",
+            Self::Fees => "Fees",
             Self::Accounts => "Accounts",
+            Self::BalanceChanges => "Balance Changes",
             Self::Instructions => "Instructions",
             Self::TokenTransfers => "Token Transfers",
             Self::Logs => "Logs",
         }
     }
+
+    /// Whether the tab has nothing to show for `data`.
+    pub fn is_empty_for(&self, data: &solana::TransactionData) -> bool {
+        match self {
+            Self::Overview | Self::Fees | Self::Accounts => false,
+            Self::BalanceChanges => !data.accounts.iter().any(|acc| {
+                acc.pre_balance
+                    .zip(acc.post_balance)
+                    .is_some_and(|(pre, post)| pre != post)
+            }),
+            Self::Instructions => data.instructions.is_empty(),
+            Self::TokenTransfers => data.token_transfers.is_empty(),
+            Self::Logs => data.logs.is_empty(),
+        }
+    }
+}
+
+/// Encoding used when displaying raw instruction data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataEncoding {
+    Base58,
+    Hex,
+    Utf8,
+}
+
+impl DataEncoding {
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Base58 => Self::Hex,
+            Self::Hex => Self::Utf8,
+            Self::Utf8 => Self::Base58,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Base58 => "base58",
+            Self::Hex => "hex",
+            Self::Utf8 => "utf-8",
+        }
+    }
+
+    /// Renders `bytes` in this encoding; UTF-8 replaces invalid and control bytes with `.`.
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Base58 => bs58::encode(bytes).into_string(),
+            Self::Hex => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+            Self::Utf8 => String::from_utf8_lossy(bytes)
+                .chars()
+                .map(|c| {
+                    if c.is_control() || c == char::REPLACEMENT_CHARACTER {
+                        '.'
+                    } else {
+                        c
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Account view pane that receives scroll keys.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccountPane {
+    TokenAccounts,
+    History,
+}
+
+impl AccountPane {
+    pub fn toggle(&self) -> Self {
+        match self {
+            Self::TokenAccounts => Self::History,
+            Self::History => Self::TokenAccounts,
+        }
+    }
 }
 
 impl App {
@@ -76,59 +331,815 @@ impl App {
             input: String::new(),
             input_cursor: 0,
             selected_network: Network::Mainnet,
+            custom_rpc_url: DEFAULT_CUSTOM_RPC_URL.to_string(),
+            commitment: Commitment::Confirmed,
+            rpc_timeout: DEFAULT_RPC_TIMEOUT,
             error_message: None,
             solana_client: None,
             transaction_data: None,
             account_data: None,
+            batch: Vec::new(),
+            batch_selected: 0,
             txn_scroll: 0,
-            account_scroll: 0,
+            txn_scroll_max: Cell::new(0),
+            h_scroll: 0,
+            h_scroll_max: Cell::new(0),
+            tab_areas: Cell::new([Rect::default(); TransactionTab::ALL.len()]),
+            history_selected: 0,
+            token_scroll: 0,
+            token_scroll_max: Cell::new(0),
+            account_pane: AccountPane::History,
+            show_hex_dump: false,
+            hex_scroll: 0,
+            hex_scroll_max: Cell::new(0),
+            balance_unit: BalanceUnit::Sol,
+            theme: Theme::DARK,
+            data_encoding: DataEncoding::Base58,
+            compact_view: false,
             transaction_tab: TransactionTab::Overview,
+            skip_empty_tabs: false,
+            accounts_selected: 0,
+            accounts_expanded: false,
+            balance_filter: None,
+            balance_filter_input: None,
+            quick_search: None,
+            nav_stack: Vec::new(),
+            recent_queries: Vec::new(),
+            recent_selected: None,
+            scroll_step: DEFAULT_SCROLL_STEP,
+            page_scroll_step: DEFAULT_PAGE_SCROLL_STEP,
+            show_legend: false,
+            show_about: false,
+            max_supported_transaction_version: Some(0),
+            skip_network_selection: false,
+            history_fees: false,
+            status_message: None,
+            last_export: None,
+            pending_editor: None,
+            pending_fetch: None,
+            pending_refresh: None,
+            loading_tick: 0,
+            loading_started: None,
+            auto_refresh: false,
+            last_refresh: None,
+            confirm_quit: false,
+            quit_requested: None,
         }
     }
 
-    pub fn get_input_type(&self) -> InputType {
-        let trimmed = self.input.trim();
-
-        if trimmed.len() == 88 || trimmed.len() == 87 {
-            if Signature::from_str(trimmed).is_ok() {
-                return InputType::Transaction;
+    /// RPC client for the current network and commitment, kept between queries so its
+    /// connection and caches carry over. The other fetch settings only change at startup,
+    /// before the first client is built.
+    pub fn client(&mut self) -> Arc<SolanaClient> {
+        if let Some(client) = &self.solana_client {
+            if client.network() == self.selected_network && client.commitment() == self.commitment {
+                return Arc::clone(client);
             }
         }
 
-        if Pubkey::from_str(trimmed).is_ok() {
-            return InputType::Account;
+        let client = Arc::new(
+            SolanaClient::new(self.selected_network.clone(), self.commitment)
+                .with_timeout(self.rpc_timeout)
+                .with_max_supported_transaction_version(self.max_supported_transaction_version)
+                .with_history_fees(self.history_fees),
+        );
+        self.solana_client = Some(Arc::clone(&client));
+        client
+    }
+
+    /// Selects a network, filling a blank Custom selection with the remembered URL.
+    pub fn select_network(&mut self, network: Network) {
+        self.selected_network = match network {
+            Network::Custom(url) if url.is_empty() => Network::Custom(self.custom_rpc_url.clone()),
+            Network::Custom(url) => {
+                self.custom_rpc_url = url.clone();
+                Network::Custom(url)
+            }
+            network => network,
+        };
+    }
+
+    /// Edits the Custom network's URL while it is selected.
+    pub fn edit_custom_rpc_url(&mut self, edit: impl FnOnce(&mut String)) {
+        if let Network::Custom(url) = &mut self.selected_network {
+            edit(url);
+            self.custom_rpc_url = url.clone();
         }
+    }
 
-        InputType::Unknown
+    pub fn get_input_type(&self) -> InputType {
+        validators::classify_input(&self.input)
+    }
+
+    /// Why the current input can't be looked up, if it's non-empty and unrecognised.
+    pub fn invalid_input_reason(&self) -> Option<String> {
+        let trimmed = self.input.trim();
+        if trimmed.is_empty() || self.get_input_type() != InputType::Unknown {
+            return None;
+        }
+        Some(validators::describe_invalid_input(trimmed))
     }
 
     pub fn insert_char(&mut self, c: char) {
         self.input.insert(self.input_cursor, c);
-        self.input_cursor += 1;
+        self.input_cursor += c.len_utf8();
+    }
+
+    pub fn insert_str(&mut self, text: &str) {
+        self.input.insert_str(self.input_cursor, text);
+        self.input_cursor += text.len();
     }
 
     pub fn delete_char(&mut self) {
-        if self.input_cursor > 0 {
-            self.input.remove(self.input_cursor - 1);
-            self.input_cursor -= 1;
+        if let Some(c) = self.input[..self.input_cursor].chars().next_back() {
+            self.input_cursor -= c.len_utf8();
+            self.input.remove(self.input_cursor);
         }
     }
 
+    // Pasted text can hold any Unicode, so the cursor steps over whole chars, not bytes
     pub fn move_cursor_left(&mut self) {
-        if self.input_cursor > 0 {
-            self.input_cursor -= 1;
+        if let Some(c) = self.input[..self.input_cursor].chars().next_back() {
+            self.input_cursor -= c.len_utf8();
         }
     }
 
     pub fn move_cursor_right(&mut self) {
-        if self.input_cursor < self.input.len() {
-            self.input_cursor += 1;
+        if let Some(c) = self.input[self.input_cursor..].chars().next() {
+            self.input_cursor += c.len_utf8();
         }
     }
 
+    /// Chars before the cursor, which is the column it's drawn at.
+    pub fn input_cursor_column(&self) -> usize {
+        self.input[..self.input_cursor].chars().count()
+    }
+
+    pub fn move_cursor_to_start(&mut self) {
+        self.input_cursor = 0;
+    }
+
+    pub fn move_cursor_to_end(&mut self) {
+        self.input_cursor = self.input.len();
+    }
+
+    /// Moves the cursor to the start of the word before it. Words are runs of letters and
+    /// digits, so each signature in a comma-separated list is one word.
+    pub fn move_cursor_word_left(&mut self) {
+        self.input_cursor = self.prev_word_start();
+    }
+
+    /// Moves the cursor to the end of the word after it.
+    pub fn move_cursor_word_right(&mut self) {
+        let bytes = self.input.as_bytes();
+        let mut i = self.input_cursor;
+        while i < bytes.len() && !bytes[i].is_ascii_alphanumeric() {
+            i += 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_alphanumeric() {
+            i += 1;
+        }
+        self.input_cursor = i;
+    }
+
+    /// Deletes from the start of the word before the cursor up to the cursor (Ctrl+W).
+    pub fn delete_word_before_cursor(&mut self) {
+        let start = self.prev_word_start();
+        self.input.replace_range(start..self.input_cursor, "");
+        self.input_cursor = start;
+    }
+
+    /// Deletes everything before the cursor (Ctrl+U).
+    pub fn delete_to_start(&mut self) {
+        self.input.replace_range(..self.input_cursor, "");
+        self.input_cursor = 0;
+    }
+
+    /// Start of the word before the cursor, skipping any separators first so repeated
+    /// presses keep moving. Stops only next to ASCII letters or digits, so the result is
+    /// always a char boundary.
+    fn prev_word_start(&self) -> usize {
+        let bytes = self.input.as_bytes();
+        let mut i = self.input_cursor;
+        while i > 0 && !bytes[i - 1].is_ascii_alphanumeric() {
+            i -= 1;
+        }
+        while i > 0 && bytes[i - 1].is_ascii_alphanumeric() {
+            i -= 1;
+        }
+        i
+    }
+
+    /// Accounts tab rows in display order (grouped by privilege), without those hidden
+    /// by the balance filter.
+    pub fn listed_accounts(&self) -> Vec<usize> {
+        let Some(data) = &self.transaction_data else {
+            return Vec::new();
+        };
+        data.grouped_account_indices()
+            .into_iter()
+            .filter(|&i| self.passes_balance_filter(&data.accounts[i]))
+            .collect()
+    }
+
+    pub fn select_next_account(&mut self) {
+        let listed = self.listed_accounts();
+        let next = match listed.iter().position(|&i| i == self.accounts_selected) {
+            Some(pos) => listed.get(pos + 1),
+            None => listed.first(),
+        };
+        if let Some(&next) = next {
+            self.accounts_selected = next;
+        }
+    }
+
+    pub fn select_prev_account(&mut self) {
+        let listed = self.listed_accounts();
+        let prev = match listed.iter().position(|&i| i == self.accounts_selected) {
+            Some(pos) => pos.checked_sub(1).and_then(|pos| listed.get(pos)),
+            None => listed.first(),
+        };
+        if let Some(&prev) = prev {
+            self.accounts_selected = prev;
+        }
+    }
+
+    /// Whether an account's absolute balance change meets the Accounts tab threshold.
+    /// Accounts without balance data are hidden while a threshold is set.
+    pub fn passes_balance_filter(&self, account: &solana::AccountMeta) -> bool {
+        let Some(threshold) = self.balance_filter else {
+            return true;
+        };
+        match (account.pre_balance, account.post_balance) {
+            (Some(pre), Some(post)) => pre.abs_diff(post) >= threshold,
+            _ => false,
+        }
+    }
+
+    /// Parses the threshold being typed (in SOL) and applies it; an empty entry clears it.
+    pub fn apply_balance_filter_input(&mut self) -> anyhow::Result<()> {
+        let input = self.balance_filter_input.take().unwrap_or_default();
+        let input = input.trim();
+        if input.is_empty() {
+            self.balance_filter = None;
+            return Ok(());
+        }
+
+        let sol: f64 = input
+            .parse()
+            .ok()
+            .filter(|sol: &f64| sol.is_finite() && *sol >= 0.0)
+            .ok_or_else(|| {
+                anyhow::anyhow!("Invalid threshold '{}': expected an amount in SOL", input)
+            })?;
+        self.balance_filter = Some((sol * 1_000_000_000.0).round() as u64);
+
+        // Keep the selection on a visible account
+        let listed = self.listed_accounts();
+        if !listed.contains(&self.accounts_selected) {
+            if let Some(&first) = listed.first() {
+                self.accounts_selected = first;
+            }
+        }
+        Ok(())
+    }
+
+    /// Scrolls the logs so the next "Program ... invoke" line is at the top.
+    pub fn jump_to_next_program(&mut self) {
+        let Some(data) = &self.transaction_data else {
+            return;
+        };
+        if let Some(offset) = data
+            .logs
+            .iter()
+            .skip(self.txn_scroll + 1)
+            .position(|log| is_program_invoke(log))
+        {
+            self.txn_scroll = (self.txn_scroll + offset + 1).min(self.txn_scroll_max.get());
+        }
+    }
+
+    /// Scrolls the logs so the previous "Program ... invoke" line is at the top.
+    pub fn jump_to_prev_program(&mut self) {
+        let Some(data) = &self.transaction_data else {
+            return;
+        };
+        let end = self.txn_scroll.min(data.logs.len());
+        if let Some(index) = data.logs[..end]
+            .iter()
+            .rposition(|log| is_program_invoke(log))
+        {
+            self.txn_scroll = index;
+        }
+    }
+
+    /// Moves the recent-transactions cursor by `delta` rows, clamped to the list.
+    pub fn move_history_selection(&mut self, delta: isize) {
+        let count = self
+            .account_data
+            .as_ref()
+            .map_or(0, |data| data.recent_transactions.len());
+        let max = count.saturating_sub(1) as isize;
+        self.history_selected = (self.history_selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    pub fn scroll_token_accounts(&mut self, delta: isize) {
+        let max = self.token_scroll_max.get() as isize;
+        self.token_scroll = (self.token_scroll as isize + delta).clamp(0, max) as usize;
+    }
+
+    /// Switches to the next tab, or the previous one if `forward` is false, rewinding its
+    /// scroll. Empty tabs are passed over when `skip_empty_tabs` is set.
+    pub fn cycle_tab(&mut self, forward: bool) {
+        let step = |tab: TransactionTab| if forward { tab.next() } else { tab.prev() };
+        let mut tab = step(self.transaction_tab);
+        let data = self.transaction_data.as_ref();
+        if let Some(data) = data.filter(|_| self.skip_empty_tabs) {
+            // The overview is never empty, so this stops within one lap
+            while tab != self.transaction_tab && tab.is_empty_for(data) {
+                tab = step(tab);
+            }
+        }
+        self.transaction_tab = tab;
+        self.txn_scroll = 0;
+        self.h_scroll = 0;
+    }
+
+    /// Scrolls the transaction tab, stopping once its last line is in view.
+    pub fn scroll_txn(&mut self, delta: isize) {
+        let max = self.txn_scroll_max.get() as isize;
+        self.txn_scroll = (self.txn_scroll as isize + delta).clamp(0, max) as usize;
+    }
+
+    /// Scrolls the transaction tab sideways by `steps` presses, stopping once the longest
+    /// line's end is in view.
+    pub fn scroll_txn_horizontal(&mut self, steps: isize) {
+        let max = self.h_scroll_max.get() as isize;
+        let delta = steps * H_SCROLL_STEP as isize;
+        self.h_scroll = (self.h_scroll as isize + delta).clamp(0, max) as usize;
+    }
+
+    /// Tab whose title was drawn at the given terminal cell.
+    pub fn tab_at(&self, column: u16, row: u16) -> Option<TransactionTab> {
+        let areas = self.tab_areas.get();
+        TransactionTab::ALL
+            .into_iter()
+            .zip(areas)
+            .find(|(_, area)| {
+                (area.left()..area.right()).contains(&column)
+                    && (area.top()..area.bottom()).contains(&row)
+            })
+            .map(|(tab, _)| tab)
+    }
+
+    /// Jumps to the bottom of the transaction tab, or selects the last listed account.
+    pub fn scroll_txn_to_end(&mut self) {
+        if self.transaction_tab != TransactionTab::Accounts {
+            self.txn_scroll = self.txn_scroll_max.get();
+            return;
+        }
+        if let Some(&last) = self.listed_accounts().last() {
+            self.accounts_selected = last;
+        }
+    }
+
+    /// Jumps to the bottom of whichever account view pane has focus.
+    pub fn scroll_account_pane_to_end(&mut self) {
+        if self.show_hex_dump {
+            self.hex_scroll = self.hex_scroll_max.get();
+            return;
+        }
+        match self.account_pane {
+            AccountPane::TokenAccounts => self.token_scroll = self.token_scroll_max.get(),
+            AccountPane::History => {
+                let count = self
+                    .account_data
+                    .as_ref()
+                    .map_or(0, |data| data.recent_transactions.len());
+                self.history_selected = count.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Jumps to the top of whichever account view pane has focus.
+    pub fn scroll_account_pane_to_start(&mut self) {
+        if self.show_hex_dump {
+            self.hex_scroll = 0;
+            return;
+        }
+        match self.account_pane {
+            AccountPane::TokenAccounts => self.token_scroll = 0,
+            AccountPane::History => self.history_selected = 0,
+        }
+    }
+
+    /// Scrolls whichever account view pane has focus.
+    pub fn scroll_account_pane(&mut self, delta: isize) {
+        if self.show_hex_dump {
+            let max = self.hex_scroll_max.get() as isize;
+            self.hex_scroll = (self.hex_scroll as isize + delta).clamp(0, max) as usize;
+            return;
+        }
+        match self.account_pane {
+            AccountPane::TokenAccounts => self.scroll_token_accounts(delta),
+            AccountPane::History => self.move_history_selection(delta),
+        }
+    }
+
+    pub fn selected_history_signature(&self) -> Option<Signature> {
+        self.account_data
+            .as_ref()?
+            .recent_transactions
+            .get(self.history_selected)
+            .map(|txn| txn.signature)
+    }
+
+    pub fn increase_scroll_step(&mut self) {
+        if self.scroll_step < MAX_SCROLL_STEP {
+            self.scroll_step += 1;
+        }
+    }
+
+    pub fn decrease_scroll_step(&mut self) {
+        if self.scroll_step > 1 {
+            self.scroll_step -= 1;
+        }
+    }
+
+    pub fn increase_page_scroll_step(&mut self) {
+        self.page_scroll_step =
+            (self.page_scroll_step + PAGE_SCROLL_STEP_INCREMENT).min(MAX_PAGE_SCROLL_STEP);
+        self.set_status(format!(
+            "PageUp/PageDown scroll {} lines",
+            self.page_scroll_step
+        ));
+    }
+
+    pub fn decrease_page_scroll_step(&mut self) {
+        self.page_scroll_step = self
+            .page_scroll_step
+            .saturating_sub(PAGE_SCROLL_STEP_INCREMENT)
+            .max(PAGE_SCROLL_STEP_INCREMENT);
+        self.set_status(format!(
+            "PageUp/PageDown scroll {} lines",
+            self.page_scroll_step
+        ));
+    }
+
+    /// Restores saved scroll steps, clamped to the range the keybinds allow.
+    pub fn set_scroll_steps(&mut self, step: Option<usize>, page_step: Option<usize>) {
+        if let Some(step) = step {
+            self.scroll_step = step.clamp(1, MAX_SCROLL_STEP);
+        }
+        if let Some(page_step) = page_step {
+            self.page_scroll_step = page_step.clamp(1, MAX_PAGE_SCROLL_STEP);
+        }
+    }
+
+    pub fn set_status(&mut self, text: impl Into<String>) {
+        self.status_message = Some(StatusMessage {
+            text: text.into(),
+            is_error: false,
+            created_at: Instant::now(),
+        });
+    }
+
+    pub fn set_error_status(&mut self, text: impl Into<String>) {
+        self.status_message = Some(StatusMessage {
+            text: text.into(),
+            is_error: true,
+            created_at: Instant::now(),
+        });
+    }
+
+    /// The current status line, if it hasn't expired yet.
+    pub fn active_status(&self) -> Option<&StatusMessage> {
+        self.status_message
+            .as_ref()
+            .filter(|msg| msg.created_at.elapsed() < STATUS_MESSAGE_TTL)
+    }
+
     pub fn clear_input(&mut self) {
         self.input.clear();
         self.input_cursor = 0;
+        self.recent_selected = None;
+    }
+
+    pub fn cycle_theme(&mut self) {
+        self.theme = self.theme.next();
+        self.set_status(format!("Theme: {}", self.theme.name));
+    }
+
+    /// Moves the input screen's suggestion cursor, copying the chosen entry into the input.
+    /// Moving up past the first suggestion returns to an empty input.
+    pub fn select_recent(&mut self, delta: isize) {
+        if self.recent_queries.is_empty() {
+            return;
+        }
+        let next = match self.recent_selected {
+            None if delta > 0 => Some(0),
+            None => return,
+            Some(i) => {
+                let i = i as isize + delta;
+                if i < 0 {
+                    None
+                } else {
+                    Some((i as usize).min(self.recent_queries.len() - 1))
+                }
+            }
+        };
+        self.recent_selected = next;
+        self.input = next
+            .map(|i| self.recent_queries[i].clone())
+            .unwrap_or_default();
+        self.input_cursor = self.input.len();
+    }
+
+    fn remember_query(&mut self, query: String) {
+        self.recent_queries.retain(|q| *q != query);
+        self.recent_queries.insert(0, query);
+        self.recent_queries.truncate(MAX_RECENT_QUERIES);
+    }
+
+    /// Saves the current result view on the navigation stack and rewinds scroll and
+    /// selection for the view about to be fetched.
+    pub fn push_nav(&mut self) {
+        if !matches!(
+            self.screen,
+            Screen::Transaction | Screen::Account | Screen::Batch
+        ) {
+            return;
+        }
+        if self.nav_stack.len() == MAX_NAV_DEPTH {
+            self.nav_stack.remove(0);
+        }
+        self.nav_stack.push(NavEntry {
+            screen: self.screen.clone(),
+            input: self.input.clone(),
+            transaction_data: self.transaction_data.clone(),
+            account_data: self.account_data.clone(),
+            transaction_tab: self.transaction_tab,
+            txn_scroll: self.txn_scroll,
+            accounts_selected: self.accounts_selected,
+            history_selected: self.history_selected,
+            token_scroll: self.token_scroll,
+            account_pane: self.account_pane,
+            show_hex_dump: self.show_hex_dump,
+            hex_scroll: self.hex_scroll,
+        });
+
+        self.transaction_tab = TransactionTab::Overview;
+        self.txn_scroll = 0;
+        self.h_scroll = 0;
+        self.accounts_selected = 0;
+        self.accounts_expanded = false;
+        self.history_selected = 0;
+        self.token_scroll = 0;
+        self.account_pane = AccountPane::History;
+        self.show_hex_dump = false;
+        self.hex_scroll = 0;
+    }
+
+    /// Restores the most recently saved view. Returns false if the stack is empty.
+    pub fn pop_nav(&mut self) -> bool {
+        let Some(entry) = self.nav_stack.pop() else {
+            return false;
+        };
+        self.screen = entry.screen;
+        self.input = entry.input;
+        self.input_cursor = self.input.len();
+        self.transaction_data = entry.transaction_data;
+        self.account_data = entry.account_data;
+        self.transaction_tab = entry.transaction_tab;
+        self.txn_scroll = entry.txn_scroll;
+        self.h_scroll = 0;
+        self.accounts_selected = entry.accounts_selected;
+        self.accounts_expanded = false;
+        self.history_selected = entry.history_selected;
+        self.token_scroll = entry.token_scroll;
+        self.account_pane = entry.account_pane;
+        self.show_hex_dump = entry.show_hex_dump;
+        self.hex_scroll = entry.hex_scroll;
+        true
+    }
+
+    /// Marks the start of a fetch for the loading screen's spinner and timer.
+    pub fn start_loading(&mut self) {
+        self.screen = Screen::Loading;
+        self.loading_tick = 0;
+        self.loading_started = Some(Instant::now());
+    }
+
+    pub fn advance_loading_tick(&mut self) {
+        if self.screen == Screen::Loading {
+            self.loading_tick = self.loading_tick.wrapping_add(1);
+        }
+    }
+
+    /// Applies the background fetch's result once it arrives.
+    pub fn poll_fetch(&mut self) {
+        self.poll_refresh();
+        let Some(receiver) = &self.pending_fetch else {
+            return;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                self.pending_fetch = None;
+                // A background history page or refresh shouldn't throw away the open view
+                let message = stopped_without_result("Fetch");
+                if self.screen == Screen::Loading {
+                    self.screen = Screen::Error(message);
+                } else {
+                    self.set_error_status(message);
+                }
+                return;
+            }
+        };
+        self.pending_fetch = None;
+
+        match result {
+            FetchResult::Transaction(Ok(data)) => {
+                self.remember_query(data.signature.to_string());
+                self.transaction_data = Some(data);
+                self.screen = Screen::Transaction;
+            }
+            FetchResult::Transaction(Err(e)) => {
+                self.screen = Screen::Error(format!("Failed to fetch transaction: {}", e));
+            }
+            FetchResult::Account(Ok(data)) => {
+                self.remember_query(data.pubkey.to_string());
+                self.account_data = Some(data);
+                self.screen = Screen::Account;
+            }
+            FetchResult::Account(Err(e)) => {
+                self.screen = Screen::Error(format!("Failed to fetch account: {}", e));
+            }
+            FetchResult::Batch(entries) => {
+                self.remember_query(self.input.trim().to_string());
+                self.batch = entries;
+                self.batch_selected = 0;
+                self.screen = Screen::Batch;
+            }
+            FetchResult::History { pubkey, result } => self.append_history(pubkey, result),
+        }
+    }
+
+    fn poll_refresh(&mut self) {
+        let Some(receiver) = &self.pending_refresh else {
+            return;
+        };
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => {
+                Err(anyhow::anyhow!(stopped_without_result("refresh")))
+            }
+        };
+        self.pending_refresh = None;
+        self.apply_refresh(result);
+    }
+
+    /// Whether Esc on a result screen should quit now. With `confirm_quit` set, the first
+    /// press only shows a prompt, and quitting takes a second press before it expires.
+    pub fn confirm_quit_request(&mut self) -> bool {
+        if !self.confirm_quit
+            || self
+                .quit_requested
+                .is_some_and(|at| at.elapsed() < STATUS_MESSAGE_TTL)
+        {
+            return true;
+        }
+        self.quit_requested = Some(Instant::now());
+        self.set_status("Press Esc again to quit");
+        false
+    }
+
+    pub fn toggle_auto_refresh(&mut self) {
+        self.auto_refresh = !self.auto_refresh;
+        self.last_refresh = None;
+        if self.auto_refresh {
+            self.set_status(format!(
+                "Auto-refresh on, every {}s",
+                AUTO_REFRESH_INTERVAL.as_secs()
+            ));
+        } else {
+            self.set_status("Auto-refresh off");
+        }
+    }
+
+    /// Whether the account view is due for its next auto-refresh.
+    pub fn refresh_due(&self) -> bool {
+        self.auto_refresh
+            && self.pending_refresh.is_none()
+            && !self
+                .last_refresh
+                .is_some_and(|last| last.elapsed() < AUTO_REFRESH_INTERVAL)
+    }
+
+    /// Swaps in a re-fetched copy of the account view, keeping the selected transaction
+    /// selected and any older history pages already loaded.
+    fn apply_refresh(&mut self, result: anyhow::Result<solana::AccountData>) {
+        self.last_refresh = Some(Instant::now());
+        let mut fresh = match result {
+            Ok(fresh) => fresh,
+            Err(e) => {
+                self.set_error_status(format!("Auto-refresh failed: {}", e));
+                return;
+            }
+        };
+        let Some(data) = self
+            .account_data
+            .as_mut()
+            .filter(|d| d.pubkey == fresh.pubkey)
+        else {
+            return;
+        };
+
+        let selected = data
+            .recent_transactions
+            .get(self.history_selected)
+            .map(|txn| txn.signature);
+        let older: Vec<_> = data
+            .recent_transactions
+            .drain(..)
+            .filter(|txn| {
+                !fresh
+                    .recent_transactions
+                    .iter()
+                    .any(|new| new.signature == txn.signature)
+            })
+            .collect();
+        if !older.is_empty() {
+            // The old cursor still points past the oldest entry kept
+            fresh.history_before = data.history_before;
+        }
+        fresh.recent_transactions.extend(older);
+
+        self.history_selected = selected
+            .and_then(|signature| {
+                fresh
+                    .recent_transactions
+                    .iter()
+                    .position(|txn| txn.signature == signature)
+            })
+            .unwrap_or(0);
+        *data = fresh;
+    }
+
+    pub fn move_batch_selection(&mut self, delta: isize) {
+        let max = self.batch.len().saturating_sub(1) as isize;
+        self.batch_selected = (self.batch_selected as isize + delta).clamp(0, max) as usize;
+    }
+
+    /// Shows the selected batch transaction, keeping the list on the navigation stack so
+    /// Backspace returns to it.
+    pub fn open_batch_entry(&mut self) {
+        let Some(entry) = self.batch.get(self.batch_selected) else {
+            return;
+        };
+        match &entry.result {
+            Ok(data) => {
+                let (signature, data) = (entry.signature.clone(), data.clone());
+                self.push_nav();
+                self.input = signature;
+                self.input_cursor = self.input.len();
+                self.transaction_data = Some(data);
+                self.screen = Screen::Transaction;
+            }
+            Err(e) => self.set_error_status(format!("Failed to fetch this transaction: {}", e)),
+        }
+    }
+
+    /// Appends a page of older transactions to the account view, if it still shows `pubkey`.
+    fn append_history(
+        &mut self,
+        pubkey: Pubkey,
+        result: anyhow::Result<Vec<solana::TransactionSummary>>,
+    ) {
+        let Some(data) = self.account_data.as_mut().filter(|d| d.pubkey == pubkey) else {
+            return;
+        };
+        match result {
+            Ok(page) => {
+                data.history_before =
+                    solana::client::next_history_cursor(&page, data.history_page_size);
+                let count = page.len();
+                data.recent_transactions.extend(page);
+                self.set_status(format!("Loaded {} more transactions", count));
+            }
+            Err(e) => self.set_error_status(format!("Failed to load more transactions: {}", e)),
+        }
+    }
+
+    /// Abandons the in-flight fetch and returns to the input screen. The request itself
+    /// can't be interrupted; its result is discarded when it lands.
+    pub fn cancel_fetch(&mut self) {
+        self.pending_fetch = None;
+        self.pending_refresh = None;
+        self.nav_stack.clear();
+        self.screen = Screen::Input;
     }
 
     pub fn reset(&mut self) {
@@ -137,19 +1148,72 @@ impl App {
         self.error_message = None;
         self.transaction_data = None;
         self.account_data = None;
+        self.batch.clear();
+        self.batch_selected = 0;
         self.txn_scroll = 0;
-        self.account_scroll = 0;
+        self.h_scroll = 0;
+        self.history_selected = 0;
+        self.token_scroll = 0;
+        self.account_pane = AccountPane::History;
+        self.show_hex_dump = false;
+        self.hex_scroll = 0;
         self.transaction_tab = TransactionTab::Overview;
+        self.accounts_selected = 0;
+        self.accounts_expanded = false;
+        self.balance_filter = None;
+        self.balance_filter_input = None;
+        self.quick_search = None;
+        self.nav_stack.clear();
+        self.pending_fetch = None;
+        self.pending_refresh = None;
+        self.loading_tick = 0;
+        self.loading_started = None;
+        self.show_legend = false;
+        self.status_message = None;
+        self.auto_refresh = false;
+        self.last_refresh = None;
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum InputType {
-    Transaction,
-    Account,
-    Unknown,
-}
-
 pub mod solana {
     pub use super::super::solana::*;
 }
+
+fn is_program_invoke(log: &str) -> bool {
+    log.starts_with("Program ") && log.contains(" invoke [")
+}
+
+/// Why a fetch thread hung up without sending a result, naming the panic if it hit one.
+fn stopped_without_result(what: &str) -> String {
+    match WORKER_PANIC.lock().ok().and_then(|mut panic| panic.take()) {
+        Some(panic) => format!("{} crashed: {}", what, panic),
+        None => format!("{} stopped without a result", what),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_edits_whole_chars_of_pasted_unicode() {
+        let mut app = App::new();
+        app.insert_str("a“b");
+        assert_eq!(app.input_cursor_column(), 3);
+
+        app.move_cursor_left();
+        app.move_cursor_left();
+        assert_eq!(app.input_cursor_column(), 1);
+        app.insert_char('é');
+        assert_eq!(app.input, "aé“b");
+
+        app.move_cursor_right();
+        app.delete_char();
+        assert_eq!(app.input, "aéb");
+        assert_eq!(app.input_cursor_column(), 2);
+
+        app.move_cursor_to_end();
+        app.move_cursor_right();
+        assert_eq!(app.input_cursor, app.input.len());
+    }
+}
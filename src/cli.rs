@@ -0,0 +1,229 @@
+use crate::app::App;
+use crate::solana::{Commitment, Network};
+use crate::utils::validators;
+use anyhow::{anyhow, bail, Result};
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: solana-txn-tui [OPTIONS] [SIGNATURE|ADDRESS]
+
+Arguments:
+  [SIGNATURE|ADDRESS]          Fetch this transaction or account straight away
+
+Options:
+  --network <NAME>             Cluster to query: mainnet, devnet or testnet (default: mainnet)
+  --rpc-url <URL>              Query a custom RPC endpoint instead of a public cluster
+  --commitment <LEVEL>         processed, confirmed or finalized (default: confirmed)
+  --timeout <SECONDS>          RPC request timeout (default: 30)
+  --json                       Print the transaction or account as JSON and exit
+  --labels <FILE>              Address labels JSON to merge over the built-in ones (repeatable)
+  --max-tx-version <N|legacy>  Highest transaction version to request (default: 0)
+  --skip-network-select        Query the default network straight from the input screen
+  --history-fees               Fetch the fee of every transaction in an account's history
+  -h, --help                   Print this help and exit
+
+Environment:
+  SOLANA_TXN_TUI_RPC_URL       Default RPC endpoint, used when no --network or --rpc-url is given
+  SOLANA_TXN_TUI_NETWORK       Default cluster (mainnet, devnet or testnet), if no RPC URL is set";
+
+/// Environment variables consulted for the network when no flag chooses one.
+const RPC_URL_ENV: &str = "SOLANA_TXN_TUI_RPC_URL";
+const NETWORK_ENV: &str = "SOLANA_TXN_TUI_NETWORK";
+
+#[derive(Debug, Clone)]
+pub struct Cli {
+    pub max_supported_transaction_version: Option<u8>,
+    pub skip_network_selection: bool,
+    pub history_fees: bool,
+    pub json: bool,
+    pub target: Option<String>,
+    pub label_files: Vec<PathBuf>,
+    pub network: Option<Network>,
+    pub commitment: Option<Commitment>,
+    pub timeout: Option<Duration>,
+    pub show_help: bool,
+}
+
+impl Default for Cli {
+    fn default() -> Self {
+        Self {
+            max_supported_transaction_version: Some(0),
+            skip_network_selection: false,
+            history_fees: false,
+            json: false,
+            target: None,
+            label_files: Vec::new(),
+            network: None,
+            commitment: None,
+            timeout: None,
+            show_help: false,
+        }
+    }
+}
+
+impl Cli {
+    pub fn parse() -> Result<Self> {
+        let mut cli = Self::parse_from(std::env::args().skip(1))?;
+        cli.network_from_env()?;
+        Ok(cli)
+    }
+
+    /// Falls back to the environment for the network, so flags win over env vars, which
+    /// win over the built-in default. An RPC URL wins over a network name.
+    fn network_from_env(&mut self) -> Result<()> {
+        if self.network.is_some() {
+            return Ok(());
+        }
+        let non_empty = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .filter(|value| !value.trim().is_empty())
+        };
+
+        if let Some(url) = non_empty(RPC_URL_ENV) {
+            let url = url.trim().to_string();
+            if !validators::is_valid_rpc_url(&url) {
+                bail!("Invalid {} '{}': expected an http(s) URL", RPC_URL_ENV, url);
+            }
+            self.network = Some(Network::Custom(url));
+        } else if let Some(name) = non_empty(NETWORK_ENV) {
+            self.network = Some(Network::from_name(name.trim()).ok_or_else(|| {
+                anyhow!(
+                    "Invalid {} '{}': expected mainnet, devnet or testnet",
+                    NETWORK_ENV,
+                    name
+                )
+            })?);
+        }
+        Ok(())
+    }
+
+    fn parse_from<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut cli = Cli::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => cli.show_help = true,
+                "--json" => cli.json = true,
+                "--skip-network-select" => cli.skip_network_selection = true,
+                "--history-fees" => cli.history_fees = true,
+                "--labels" => {
+                    let path = value_for(&mut args, "--labels")?;
+                    cli.label_files.push(PathBuf::from(path));
+                }
+                "--network" => {
+                    let name = value_for(&mut args, "--network")?;
+                    if matches!(cli.network, Some(Network::Custom(_))) {
+                        bail!("--network and --rpc-url cannot be combined");
+                    }
+                    cli.network = Some(Network::from_name(&name).ok_or_else(|| {
+                        anyhow!(
+                            "Invalid --network '{}': expected mainnet, devnet or testnet",
+                            name
+                        )
+                    })?);
+                }
+                "--rpc-url" => {
+                    let url = value_for(&mut args, "--rpc-url")?;
+                    if cli.network.is_some() {
+                        bail!("--network and --rpc-url cannot be combined");
+                    }
+                    if !validators::is_valid_rpc_url(&url) {
+                        bail!("Invalid --rpc-url '{}': expected an http(s) URL", url);
+                    }
+                    cli.network = Some(Network::Custom(url));
+                }
+                "--commitment" => {
+                    let level = value_for(&mut args, "--commitment")?;
+                    cli.commitment = Some(Commitment::from_name(&level).ok_or_else(|| {
+                        anyhow!(
+                            "Invalid --commitment '{}': expected processed, confirmed or finalized",
+                            level
+                        )
+                    })?);
+                }
+                "--timeout" => {
+                    let value = value_for(&mut args, "--timeout")?;
+                    let seconds = value
+                        .parse::<u64>()
+                        .ok()
+                        .filter(|&seconds| seconds > 0)
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "Invalid --timeout '{}': expected a positive number of seconds",
+                                value
+                            )
+                        })?;
+                    cli.timeout = Some(Duration::from_secs(seconds));
+                }
+                "--max-tx-version" => {
+                    let value = value_for(&mut args, "--max-tx-version")?;
+                    cli.max_supported_transaction_version = parse_transaction_version(&value)?;
+                }
+                other if other.starts_with('-') => bail!("Unknown argument: {}", other),
+                other => {
+                    if cli.target.is_some() {
+                        bail!("Unexpected argument: {}", other);
+                    }
+                    cli.target = Some(other.to_string());
+                }
+            }
+        }
+
+        if cli.json && cli.target.is_none() {
+            bail!("--json requires a signature or address");
+        }
+        if let Some(target) = &cli.target {
+            let target = target.trim();
+            if !validators::is_valid_signature(target) && !validators::is_valid_pubkey(target) {
+                bail!(
+                    "Invalid argument '{}': {}",
+                    target,
+                    validators::describe_invalid_input(target)
+                );
+            }
+        }
+
+        Ok(cli)
+    }
+
+    pub fn apply(&self, app: &mut App) {
+        app.max_supported_transaction_version = self.max_supported_transaction_version;
+        app.skip_network_selection = self.skip_network_selection;
+        app.history_fees = self.history_fees;
+        if let Some(network) = &self.network {
+            app.select_network(network.clone());
+        }
+        if let Some(commitment) = self.commitment {
+            app.commitment = commitment;
+        }
+        if let Some(timeout) = self.timeout {
+            app.rpc_timeout = timeout;
+        }
+
+        if let Some(target) = &self.target {
+            app.input = target.trim().to_string();
+            app.input_cursor = app.input.len();
+        }
+    }
+}
+
+fn value_for(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String> {
+    args.next()
+        .ok_or_else(|| anyhow!("{} requires a value", flag))
+}
+
+fn parse_transaction_version(value: &str) -> Result<Option<u8>> {
+    if value.eq_ignore_ascii_case("legacy") || value.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+
+    value.parse::<u8>().map(Some).map_err(|_| {
+        anyhow!(
+            "Invalid --max-tx-version '{}': expected a number or 'legacy'",
+            value
+        )
+    })
+}
@@ -0,0 +1,113 @@
+use crate::app::App;
+use crate::solana::{Commitment, Network};
+use crate::ui::Theme;
+use crate::utils::validators;
+use anyhow::{Context, Result};
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Settings remembered between sessions, stored as TOML in the OS config directory
+/// (e.g. `~/.config/solana-txn-tui/config.toml` on Linux).
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Last used network: mainnet, devnet, testnet or custom
+    pub network: Option<String>,
+    /// Endpoint of the custom network, kept even while another network is selected
+    pub rpc_url: Option<String>,
+    pub commitment: Option<String>,
+    /// Color theme: dark, light or high-contrast
+    pub theme: Option<String>,
+    /// Recently fetched signatures and addresses, most recent first
+    pub recent: Vec<String>,
+    /// Tab and Shift+Tab pass over transaction tabs with nothing to show
+    pub skip_empty_tabs: bool,
+    /// Ask for a second Esc before quitting from a result screen
+    pub confirm_quit: bool,
+    /// Lines a single Up/Down press scrolls
+    pub scroll_step: Option<usize>,
+    /// Lines a PageUp/PageDown press scrolls
+    pub page_scroll_step: Option<usize>,
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        ProjectDirs::from("", "", "solana-txn-tui")
+            .map(|dirs| dirs.config_dir().join("config.toml"))
+    }
+
+    /// Reads the config file, or returns the defaults if there isn't one yet.
+    pub fn load() -> Result<Self> {
+        let Some(path) = Self::path() else {
+            return Ok(Self::default());
+        };
+        match fs::read_to_string(&path) {
+            Ok(contents) => toml::from_str(&contents)
+                .with_context(|| format!("Invalid config file {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = Self::path() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        fs::write(&path, toml::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Restores the saved settings. Applied before the command line, so flags and
+    /// environment variables still take precedence. Unrecognized values are ignored.
+    pub fn apply(&self, app: &mut App) {
+        if let Some(url) = self
+            .rpc_url
+            .as_ref()
+            .filter(|u| validators::is_valid_rpc_url(u))
+        {
+            app.custom_rpc_url = url.clone();
+        }
+        match self.network.as_deref() {
+            Some("custom") => app.select_network(Network::Custom(String::new())),
+            Some(name) => {
+                if let Some(network) = Network::from_name(name) {
+                    app.select_network(network);
+                }
+            }
+            None => {}
+        }
+        if let Some(commitment) = self.commitment.as_deref().and_then(Commitment::from_name) {
+            app.commitment = commitment;
+        }
+        if let Some(theme) = self.theme.as_deref().and_then(Theme::from_name) {
+            app.theme = theme;
+        }
+        app.recent_queries = self.recent.clone();
+        app.recent_queries.truncate(crate::app::MAX_RECENT_QUERIES);
+        app.skip_empty_tabs = self.skip_empty_tabs;
+        app.confirm_quit = self.confirm_quit;
+        app.set_scroll_steps(self.scroll_step, self.page_scroll_step);
+    }
+
+    /// Settings to save when the session ends.
+    pub fn from_app(app: &App) -> Self {
+        Self {
+            network: Some(app.selected_network.name().to_lowercase()),
+            rpc_url: Some(app.custom_rpc_url.clone()),
+            commitment: Some(app.commitment.name().to_string()),
+            theme: Some(app.theme.name.to_string()),
+            recent: app.recent_queries.clone(),
+            skip_empty_tabs: app.skip_empty_tabs,
+            confirm_quit: app.confirm_quit,
+            scroll_step: Some(app.scroll_step),
+            page_scroll_step: Some(app.page_scroll_step),
+        }
+    }
+}
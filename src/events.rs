@@ -1,41 +1,143 @@
-use crate::app::{App, InputType, Screen};
-use crate::solana::SolanaClient;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crate::app::{AccountPane, App, BatchEntry, FetchResult, InputType, Screen, TransactionTab};
+use crate::solana::{Commitment, Network};
+use crate::utils::{clipboard, explorer, export, validators};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use std::sync::mpsc;
+use std::thread;
 use std::time::Duration;
 
 pub fn handle_event(app: &mut App) -> anyhow::Result<bool> {
     if event::poll(Duration::from_millis(50))? {
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
                 return handle_key_event(app, key);
             }
+            Event::Paste(text) => handle_paste(app, &text),
+            Event::Mouse(mouse) => return handle_mouse_event(app, mouse),
+            _ => {}
         }
     }
     Ok(false)
 }
 
+/// The wheel scrolls like the arrow keys; clicking a tab title on the transaction screen
+/// switches to that tab. Ignored while a popup or the balance filter is open.
+fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> anyhow::Result<bool> {
+    if app.show_legend || app.show_about || app.balance_filter_input.is_some() {
+        return Ok(false);
+    }
+    let code = match mouse.kind {
+        MouseEventKind::ScrollUp => KeyCode::Up,
+        MouseEventKind::ScrollDown => KeyCode::Down,
+        MouseEventKind::Down(MouseButton::Left) if app.screen == Screen::Transaction => {
+            if let Some(tab) = app.tab_at(mouse.column, mouse.row) {
+                if tab != app.transaction_tab {
+                    app.transaction_tab = tab;
+                    app.txn_scroll = 0;
+                    app.h_scroll = 0;
+                }
+            }
+            return Ok(false);
+        }
+        _ => return Ok(false),
+    };
+    match app.screen {
+        Screen::Transaction => handle_transaction_screen(app, KeyEvent::from(code)),
+        Screen::Account => handle_account_screen(app, KeyEvent::from(code)),
+        Screen::Batch => handle_batch_screen(app, KeyEvent::from(code)),
+        _ => Ok(false),
+    }
+}
+
 fn handle_key_event(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    if app.show_legend || app.show_about {
+        if key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL {
+            return Ok(true);
+        }
+        // Any other key dismisses the popup
+        app.show_legend = false;
+        app.show_about = false;
+        return Ok(false);
+    }
+
+    // A pending quit confirmation only holds for the very next key
+    if key.code != KeyCode::Esc {
+        app.quit_requested = None;
+    }
+
+    if key.code == KeyCode::F(1) {
+        app.show_about = true;
+        return Ok(false);
+    }
+
     match app.screen {
         Screen::Input => handle_input_screen(app, key),
         Screen::NetworkSelection => handle_network_selection_screen(app, key),
-        Screen::Loading => Ok(false),
+        Screen::Loading => handle_loading_screen(app, key),
         Screen::Transaction => handle_transaction_screen(app, key),
         Screen::Account => handle_account_screen(app, key),
+        Screen::Batch => handle_batch_screen(app, key),
         Screen::Error(_) => handle_error_screen(app, key),
     }
 }
 
+/// Inserts bracketed-paste text into whichever text field is active, in one go.
+fn handle_paste(app: &mut App, text: &str) {
+    if app.show_legend || app.show_about {
+        return;
+    }
+    // Copied signatures and addresses often drag a trailing newline along
+    let text = text.trim();
+
+    match app.screen {
+        // A pasted column of signatures becomes a batch lookup on the single-line input
+        Screen::Input => app.insert_str(&validators::batch_entries(text).join(", ")),
+        Screen::NetworkSelection => app.edit_custom_rpc_url(|url| url.push_str(text)),
+        Screen::Transaction | Screen::Account | Screen::Batch => {
+            if let Some(query) = app.quick_search.as_mut() {
+                query.push_str(text);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn handle_input_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
     match key.code {
         // Only quit on Ctrl+C or Esc, NOT on 'q'
         KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
         KeyCode::Esc => return Ok(true),
 
+        KeyCode::Char('w') if key.modifiers == KeyModifiers::CONTROL => {
+            app.delete_word_before_cursor();
+            app.recent_selected = None;
+        }
+        KeyCode::Char('u') if key.modifiers == KeyModifiers::CONTROL => {
+            app.delete_to_start();
+            app.recent_selected = None;
+        }
         KeyCode::Char(c) => {
             app.insert_char(c);
+            app.recent_selected = None;
         }
         KeyCode::Backspace => {
             app.delete_char();
+            app.recent_selected = None;
+        }
+        KeyCode::Up => {
+            app.select_recent(-1);
+        }
+        KeyCode::Down => {
+            app.select_recent(1);
+        }
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.move_cursor_word_left();
+        }
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.move_cursor_word_right();
         }
         KeyCode::Left => {
             app.move_cursor_left();
@@ -43,25 +145,36 @@ fn handle_input_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
         KeyCode::Right => {
             app.move_cursor_right();
         }
+        KeyCode::Home => {
+            app.move_cursor_to_start();
+        }
+        KeyCode::End => {
+            app.move_cursor_to_end();
+        }
         KeyCode::Enter => {
             // Move to network selection after entering input
             if !app.input.is_empty() {
                 let input_type = app.get_input_type();
                 if input_type == InputType::Unknown {
-                    app.screen = Screen::Error(
-                        "Invalid input. Must be a transaction signature (88 chars) or a public key (32-44 chars)".to_string()
-                    );
+                    app.screen = Screen::Error(invalid_input_message(app));
+                } else if app.skip_network_selection {
+                    return submit_query(app);
                 } else {
                     app.screen = Screen::NetworkSelection;
                 }
             }
         }
+        KeyCode::Tab => {
+            app.screen = Screen::NetworkSelection;
+        }
         _ => {}
     }
     Ok(false)
 }
 
 fn handle_network_selection_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    let custom = matches!(app.selected_network, Network::Custom(_));
+
     match key.code {
         KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
         KeyCode::Esc => {
@@ -69,19 +182,34 @@ fn handle_network_selection_screen(app: &mut App, key: KeyEvent) -> anyhow::Resu
             app.screen = Screen::Input;
         }
         KeyCode::Left => {
-            app.selected_network = app.selected_network.prev();
+            app.select_network(app.selected_network.prev());
         }
         KeyCode::Right => {
-            app.selected_network = app.selected_network.next();
+            app.select_network(app.selected_network.next());
         }
         KeyCode::Up => {
-            app.selected_network = app.selected_network.prev();
+            app.select_network(app.selected_network.prev());
         }
         KeyCode::Down => {
-            app.selected_network = app.selected_network.next();
+            app.select_network(app.selected_network.next());
+        }
+        KeyCode::Char(c)
+            if custom
+                && !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            app.edit_custom_rpc_url(|url| url.push(c));
+        }
+        KeyCode::Backspace if custom => {
+            app.edit_custom_rpc_url(|url| {
+                url.pop();
+            });
         }
         KeyCode::Enter => {
-            return submit_query(app);
+            if validators::is_valid_rpc_url(app.selected_network.url()) {
+                return submit_query(app);
+            }
         }
         KeyCode::Backspace => {
             // Go back to input
@@ -92,116 +220,654 @@ fn handle_network_selection_screen(app: &mut App, key: KeyEvent) -> anyhow::Resu
     Ok(false)
 }
 
-fn submit_query(app: &mut App) -> anyhow::Result<bool> {
+/// Error shown for input that is neither a signature nor an address. Signatures and
+/// addresses vary in length, so the reason comes from decoding rather than a length check.
+fn invalid_input_message(app: &App) -> String {
+    format!(
+        "Invalid input: {}. Enter a transaction signature or an account address.",
+        validators::describe_invalid_input(&app.input)
+    )
+}
+
+/// Starts fetching the current input on a background thread; the event loop picks up
+/// the result through `App::poll_fetch`.
+pub fn submit_query(app: &mut App) -> anyhow::Result<bool> {
     let input_type = app.get_input_type();
+    if input_type == InputType::Unknown {
+        app.screen = Screen::Error(invalid_input_message(app));
+        return Ok(false);
+    }
+
     let input = app.input.clone();
-    let network = app.selected_network;
+    let client = app.client();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = match input_type {
+            InputType::Transaction => FetchResult::Transaction(client.fetch_transaction(&input)),
+            // One client for the whole list, so its token name cache carries over
+            InputType::Batch => FetchResult::Batch(
+                validators::batch_entries(&input)
+                    .into_iter()
+                    .map(|signature| BatchEntry {
+                        signature: signature.to_string(),
+                        result: client
+                            .fetch_transaction(signature)
+                            .map_err(|e| e.to_string()),
+                    })
+                    .collect(),
+            ),
+            _ => FetchResult::Account(client.fetch_account(&input)),
+        };
+        // The receiver is gone if the fetch was cancelled
+        let _ = sender.send(result);
+    });
 
-    app.screen = Screen::Loading;
+    app.pending_fetch = Some(receiver);
+    app.start_loading();
+    Ok(false)
+}
 
-    match input_type {
-        InputType::Transaction => {
-            let client = SolanaClient::new(network);
-            match client.fetch_transaction(&input) {
-                Ok(data) => {
-                    app.transaction_data = Some(data);
-                    app.screen = Screen::Transaction;
-                }
-                Err(e) => {
-                    app.screen = Screen::Error(format!("Failed to fetch transaction: {}", e));
-                }
-            }
-        }
-        InputType::Account => {
-            let client = SolanaClient::new(network);
-            match client.fetch_account(&input) {
-                Ok(data) => {
-                    app.account_data = Some(data);
-                    app.screen = Screen::Account;
-                }
-                Err(e) => {
-                    app.screen = Screen::Error(format!("Failed to fetch account: {}", e));
-                }
-            }
-        }
-        InputType::Unknown => {
-            app.screen = Screen::Error(
-                "Invalid input. Must be a transaction signature (88 chars) or a public key (32-44 chars)".to_string()
-            );
-        }
+/// Fetches the next page of the account's transaction history in the background,
+/// keeping the account view on screen while it loads.
+fn load_more_history(app: &mut App) {
+    if app.pending_fetch.is_some() {
+        app.set_status("Still loading the previous page...");
+        return;
+    }
+    let Some(data) = &app.account_data else {
+        return;
+    };
+    let Some(before) = data.history_before else {
+        app.set_status("No older transactions");
+        return;
+    };
+    let (pubkey, limit) = (data.pubkey, data.history_page_size);
+
+    let client = app.client();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = client.fetch_history(&pubkey, Some(before), limit);
+        let _ = sender.send(FetchResult::History { pubkey, result });
+    });
+
+    app.pending_fetch = Some(receiver);
+    app.set_status("Loading more transactions...");
+}
+
+/// Re-fetches the account view in the background whenever auto-refresh is due. Leaving
+/// the account view turns auto-refresh off.
+pub fn auto_refresh(app: &mut App) {
+    if app.auto_refresh && app.screen != Screen::Account {
+        app.auto_refresh = false;
+        app.last_refresh = None;
     }
+    if !app.refresh_due() {
+        return;
+    }
+    let Some(data) = &app.account_data else {
+        return;
+    };
+    let pubkey = data.pubkey.to_string();
+
+    let client = app.client();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(client.fetch_account(&pubkey));
+    });
 
+    app.pending_refresh = Some(receiver);
+}
+
+fn handle_loading_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
+        KeyCode::Esc => app.cancel_fetch(),
+        _ => {}
+    }
     Ok(false)
 }
 
 fn handle_transaction_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    if app.quick_search.is_some() {
+        return handle_quick_search(app, key);
+    }
+    if app.balance_filter_input.is_some() {
+        return handle_balance_filter_input(app, key);
+    }
+
     match key.code {
         // Only quit on Ctrl+C or Esc
         KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
-        KeyCode::Esc => return Ok(true),
+        // Esc steps back through drilled-down views, and quits from the first one
+        // (asking first if `confirm_quit` is set)
+        KeyCode::Esc => {
+            if !app.pop_nav() && app.confirm_quit_request() {
+                return Ok(true);
+            }
+        }
         KeyCode::Char('r') | KeyCode::Char('R') => {
             app.reset();
         }
-        KeyCode::Up => {
-            if app.txn_scroll > 0 {
-                app.txn_scroll -= 1;
+        KeyCode::Char('/') => {
+            app.quick_search = Some(String::new());
+        }
+        KeyCode::Backspace => {
+            go_back(app);
+        }
+        KeyCode::Char('?') => {
+            app.show_legend = true;
+        }
+        KeyCode::Char('c') => {
+            return cycle_commitment(app);
+        }
+        KeyCode::Char('u') => {
+            app.balance_unit = app.balance_unit.toggle();
+        }
+        KeyCode::Char('t') => {
+            app.cycle_theme();
+        }
+        KeyCode::Char('m') => {
+            app.compact_view = !app.compact_view;
+        }
+        KeyCode::Char('d') => {
+            app.data_encoding = app.data_encoding.next();
+        }
+        KeyCode::Char('y') => {
+            copy_transaction_value(app);
+        }
+        KeyCode::Char('o') => {
+            if let Some(data) = &app.transaction_data {
+                let url =
+                    explorer::transaction_url(&data.signature.to_string(), &app.selected_network);
+                open_in_browser(app, url);
+            }
+        }
+        KeyCode::Char('s') => {
+            save_transaction_json(app);
+        }
+        KeyCode::Char('w') if app.transaction_tab == TransactionTab::Logs => {
+            save_logs(app);
+        }
+        KeyCode::Char('e') => {
+            open_last_export(app);
+        }
+        KeyCode::Char(']') if app.transaction_tab == TransactionTab::Logs => {
+            app.jump_to_next_program();
+        }
+        KeyCode::Char('[') if app.transaction_tab == TransactionTab::Logs => {
+            app.jump_to_prev_program();
+        }
+        KeyCode::Char('f') if app.transaction_tab == TransactionTab::Accounts => {
+            app.balance_filter_input = Some(String::new());
+        }
+        KeyCode::Up if app.transaction_tab == TransactionTab::Accounts => {
+            app.select_prev_account();
+        }
+        KeyCode::Down if app.transaction_tab == TransactionTab::Accounts => {
+            app.select_next_account();
+        }
+        KeyCode::Enter if app.transaction_tab == TransactionTab::Accounts => {
+            if let Some(account) = app
+                .transaction_data
+                .as_ref()
+                .and_then(|data| data.accounts.get(app.accounts_selected))
+            {
+                return navigate_to(app, account.pubkey.to_string());
             }
         }
+        KeyCode::Char(' ') if app.transaction_tab == TransactionTab::Accounts => {
+            app.accounts_expanded = !app.accounts_expanded;
+        }
+        KeyCode::Up => {
+            app.scroll_txn(-(app.scroll_step as isize));
+        }
         KeyCode::Down => {
-            app.txn_scroll += 1;
+            app.scroll_txn(app.scroll_step as isize);
+        }
+        // Scrolls long lines sideways on the Instructions and Logs tabs
+        KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            app.scroll_txn_horizontal(-1);
+        }
+        KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            app.scroll_txn_horizontal(1);
         }
         KeyCode::PageUp => {
-            app.txn_scroll = app.txn_scroll.saturating_sub(10);
+            app.scroll_txn(-(app.page_scroll_step as isize));
         }
         KeyCode::PageDown => {
-            app.txn_scroll += 10;
+            app.scroll_txn(app.page_scroll_step as isize);
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            app.increase_scroll_step();
+        }
+        KeyCode::Char('-') => {
+            app.decrease_scroll_step();
+        }
+        KeyCode::Char('>') => {
+            app.increase_page_scroll_step();
+        }
+        KeyCode::Char('<') => {
+            app.decrease_page_scroll_step();
         }
         KeyCode::Home => {
             app.txn_scroll = 0;
+            app.h_scroll = 0;
+        }
+        KeyCode::End => {
+            app.scroll_txn_to_end();
         }
         KeyCode::Tab => {
-            app.transaction_tab = app.transaction_tab.next();
-            app.txn_scroll = 0; // Reset scroll when switching tabs
+            app.cycle_tab(true);
         }
         KeyCode::BackTab => {
-            app.transaction_tab = app.transaction_tab.prev();
-            app.txn_scroll = 0;
+            app.cycle_tab(false);
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Keys while typing the Accounts tab's minimum balance change.
+fn handle_balance_filter_input(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    let Some(input) = app.balance_filter_input.as_mut() else {
+        return Ok(false);
+    };
+
+    match key.code {
+        KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
+        KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => input.push(c),
+        KeyCode::Backspace => {
+            input.pop();
+        }
+        KeyCode::Enter => {
+            if let Err(e) = app.apply_balance_filter_input() {
+                app.set_error_status(e.to_string());
+            }
         }
+        KeyCode::Esc => app.balance_filter_input = None,
         _ => {}
     }
     Ok(false)
 }
 
 fn handle_account_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    if app.quick_search.is_some() {
+        return handle_quick_search(app, key);
+    }
+
     match key.code {
         // Only quit on Ctrl+C or Esc
         KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
-        KeyCode::Esc => return Ok(true),
+        // Esc first stops auto-refresh, then steps back through drilled-down views, and
+        // quits from the first one (asking first if `confirm_quit` is set)
+        KeyCode::Esc if app.auto_refresh => {
+            app.toggle_auto_refresh();
+        }
+        KeyCode::Esc => {
+            if !app.pop_nav() && app.confirm_quit_request() {
+                return Ok(true);
+            }
+        }
         KeyCode::Char('r') | KeyCode::Char('R') => {
             app.reset();
         }
+        KeyCode::Char('/') => {
+            app.quick_search = Some(String::new());
+        }
+        KeyCode::Backspace => {
+            go_back(app);
+        }
+        KeyCode::Char('?') => {
+            app.show_legend = true;
+        }
+        KeyCode::Char('c') => {
+            return cycle_commitment(app);
+        }
+        KeyCode::Char('u') => {
+            app.balance_unit = app.balance_unit.toggle();
+        }
+        KeyCode::Char('t') => {
+            app.cycle_theme();
+        }
+        KeyCode::Char('a') => {
+            app.toggle_auto_refresh();
+        }
+        KeyCode::Char('w') => {
+            save_account_data(app);
+        }
+        KeyCode::Char('s') => {
+            save_account_json(app);
+        }
+        KeyCode::Char('x') => {
+            save_history_csv(app);
+        }
+        KeyCode::Char('e') => {
+            open_last_export(app);
+        }
+        KeyCode::Char('y') => {
+            copy_account_value(app);
+        }
+        KeyCode::Char('o') => {
+            if let Some(data) = &app.account_data {
+                let url = explorer::address_url(&data.pubkey.to_string(), &app.selected_network);
+                open_in_browser(app, url);
+            }
+        }
+        KeyCode::Char('h') => {
+            app.show_hex_dump = !app.show_hex_dump;
+        }
+        KeyCode::Char('n') => {
+            load_more_history(app);
+        }
+        KeyCode::Enter if !app.show_hex_dump => {
+            if let Some(signature) = app.selected_history_signature() {
+                return navigate_to(app, signature.to_string());
+            }
+        }
+        KeyCode::Tab | KeyCode::BackTab => {
+            app.account_pane = app.account_pane.toggle();
+        }
         KeyCode::Up => {
-            if app.account_scroll > 0 {
-                app.account_scroll -= 1;
+            app.scroll_account_pane(-(app.scroll_step as isize));
+        }
+        KeyCode::Down => {
+            app.scroll_account_pane(app.scroll_step as isize);
+        }
+        KeyCode::PageUp => {
+            app.scroll_account_pane(-(app.page_scroll_step as isize));
+        }
+        KeyCode::PageDown => {
+            app.scroll_account_pane(app.page_scroll_step as isize);
+        }
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            app.increase_scroll_step();
+        }
+        KeyCode::Char('-') => {
+            app.decrease_scroll_step();
+        }
+        KeyCode::Char('>') => {
+            app.increase_page_scroll_step();
+        }
+        KeyCode::Char('<') => {
+            app.decrease_page_scroll_step();
+        }
+        KeyCode::Home => {
+            app.scroll_account_pane_to_start();
+        }
+        KeyCode::End => {
+            app.scroll_account_pane_to_end();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Keys while typing in the `/` quick-search bar.
+fn handle_quick_search(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    let Some(query) = app.quick_search.as_mut() else {
+        return Ok(false);
+    };
+
+    match key.code {
+        KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
+        KeyCode::Char(c)
+            if !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            query.push(c)
+        }
+        KeyCode::Backspace => {
+            query.pop();
+        }
+        KeyCode::Esc => app.quick_search = None,
+        KeyCode::Enter => {
+            let query = query.trim().to_string();
+            if query.is_empty() {
+                app.quick_search = None;
+            } else if validators::is_valid_signature(&query) || validators::is_valid_pubkey(&query)
+            {
+                app.quick_search = None;
+                return navigate_to(app, query);
+            } else {
+                let reason = validators::describe_invalid_input(&query);
+                app.set_error_status(format!("Invalid input: {}", reason));
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_batch_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
+    if app.quick_search.is_some() {
+        return handle_quick_search(app, key);
+    }
+
+    match key.code {
+        KeyCode::Char('c') if key.modifiers == KeyModifiers::CONTROL => return Ok(true),
+        KeyCode::Esc => {
+            if !app.pop_nav() && app.confirm_quit_request() {
+                return Ok(true);
             }
         }
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.reset();
+        }
+        KeyCode::Char('/') => {
+            app.quick_search = Some(String::new());
+        }
+        KeyCode::Backspace => {
+            go_back(app);
+        }
+        KeyCode::Char('t') => {
+            app.cycle_theme();
+        }
+        KeyCode::Up => {
+            app.move_batch_selection(-1);
+        }
         KeyCode::Down => {
-            app.account_scroll += 1;
+            app.move_batch_selection(1);
         }
         KeyCode::PageUp => {
-            app.account_scroll = app.account_scroll.saturating_sub(10);
+            app.move_batch_selection(-(app.page_scroll_step as isize));
         }
         KeyCode::PageDown => {
-            app.account_scroll += 10;
+            app.move_batch_selection(app.page_scroll_step as isize);
         }
         KeyCode::Home => {
-            app.account_scroll = 0;
+            app.batch_selected = 0;
+        }
+        KeyCode::End => {
+            app.batch_selected = app.batch.len().saturating_sub(1);
+        }
+        KeyCode::Enter => {
+            app.open_batch_entry();
+        }
+        KeyCode::Char('y') => {
+            if let Some(entry) = app.batch.get(app.batch_selected) {
+                copy_to_clipboard(app, entry.signature.clone());
+            }
         }
         _ => {}
     }
     Ok(false)
 }
 
+/// Fetches `input` as a new view, saving the current one so Backspace can return to it.
+fn navigate_to(app: &mut App, input: String) -> anyhow::Result<bool> {
+    app.push_nav();
+    app.input = input;
+    app.input_cursor = app.input.len();
+    submit_query(app)
+}
+
+fn go_back(app: &mut App) {
+    if !app.pop_nav() {
+        app.set_error_status("Nothing to go back to");
+    }
+}
+
+/// Moves to the next commitment level and re-runs the current query under it.
+fn cycle_commitment(app: &mut App) -> anyhow::Result<bool> {
+    let mut next = app.commitment.next();
+    if app.screen == Screen::Transaction && next == Commitment::Processed {
+        // getTransaction has no processed view; skip straight past it
+        next = next.next();
+    }
+    app.commitment = next;
+    submit_query(app)
+}
+
+fn save_logs(app: &mut App) {
+    let Some(data) = &app.transaction_data else {
+        return;
+    };
+
+    let contents = data.logs.join("\n");
+    match export::write_export(&data.signature.to_string(), "log", contents.as_bytes()) {
+        Ok(path) => {
+            let msg = format!(
+                "Wrote {} log lines to {} (e to open)",
+                data.logs.len(),
+                path.display()
+            );
+            app.set_status(msg);
+            app.last_export = Some(path);
+        }
+        Err(e) => app.set_error_status(format!("Failed to write logs: {}", e)),
+    }
+}
+
+fn save_account_data(app: &mut App) {
+    let Some(data) = &app.account_data else {
+        return;
+    };
+
+    match export::write_export(&data.pubkey.to_string(), "bin", &data.data) {
+        Ok(path) => {
+            let msg = format!("Wrote {} bytes to {}", data.data.len(), path.display());
+            app.set_status(msg);
+        }
+        Err(e) => app.set_error_status(format!("Failed to write account data: {}", e)),
+    }
+}
+
+fn save_account_json(app: &mut App) {
+    let Some(data) = &app.account_data else {
+        return;
+    };
+
+    let result = serde_json::to_string_pretty(data)
+        .map_err(std::io::Error::from)
+        .and_then(|json| export::write_export(&data.pubkey.to_string(), "json", json.as_bytes()));
+    match result {
+        Ok(path) => {
+            app.set_status(format!("Wrote {} (e to open)", path.display()));
+            app.last_export = Some(path);
+        }
+        Err(e) => app.set_error_status(format!("Failed to write account JSON: {}", e)),
+    }
+}
+
+fn save_history_csv(app: &mut App) {
+    let Some(data) = &app.account_data else {
+        return;
+    };
+    if data.recent_transactions.is_empty() {
+        app.set_error_status("No transactions to export");
+        return;
+    }
+
+    let count = data.recent_transactions.len();
+    let csv = export::transactions_csv(&data.recent_transactions);
+    let stem = format!("{}-transactions", data.pubkey);
+    match export::write_export(&stem, "csv", csv.as_bytes()) {
+        Ok(path) => {
+            app.set_status(format!(
+                "Wrote {} transactions to {} (e to open)",
+                count,
+                path.display()
+            ));
+            app.last_export = Some(path);
+        }
+        Err(e) => app.set_error_status(format!("Failed to write CSV: {}", e)),
+    }
+}
+
+fn save_transaction_json(app: &mut App) {
+    let Some(data) = &app.transaction_data else {
+        return;
+    };
+
+    let result = serde_json::to_string_pretty(data)
+        .map_err(std::io::Error::from)
+        .and_then(|json| {
+            export::write_export(&data.signature.to_string(), "json", json.as_bytes())
+        });
+    match result {
+        Ok(path) => {
+            app.set_status(format!("Wrote {} (e to open)", path.display()));
+            app.last_export = Some(path);
+        }
+        Err(e) => {
+            app.screen = Screen::Error(format!("Failed to write transaction JSON: {}", e));
+        }
+    }
+}
+
+/// Asks the main loop to open the most recent export in `$EDITOR`.
+fn open_last_export(app: &mut App) {
+    match &app.last_export {
+        Some(path) => app.pending_editor = Some(path.clone()),
+        None => app.set_error_status("Nothing exported yet"),
+    }
+}
+
+/// Copies the highlighted Accounts row's pubkey, or else the transaction signature.
+fn copy_transaction_value(app: &mut App) {
+    let Some(data) = &app.transaction_data else {
+        return;
+    };
+
+    let value = match data.accounts.get(app.accounts_selected) {
+        Some(account) if app.transaction_tab == TransactionTab::Accounts => {
+            account.pubkey.to_string()
+        }
+        _ => data.signature.to_string(),
+    };
+    copy_to_clipboard(app, value);
+}
+
+/// Copies the selected recent transaction's signature when the history pane has focus,
+/// or else the account's pubkey.
+fn copy_account_value(app: &mut App) {
+    let value = match app.selected_history_signature() {
+        Some(signature) if app.account_pane == AccountPane::History && !app.show_hex_dump => {
+            signature.to_string()
+        }
+        _ => match &app.account_data {
+            Some(data) => data.pubkey.to_string(),
+            None => return,
+        },
+    };
+    copy_to_clipboard(app, value);
+}
+
+fn open_in_browser(app: &mut App, url: String) {
+    match explorer::open(&url) {
+        Ok(()) => app.set_status(format!("Opened {}", url)),
+        Err(e) => app.set_error_status(format!("Failed to open browser: {}", e)),
+    }
+}
+
+fn copy_to_clipboard(app: &mut App, value: String) {
+    match clipboard::copy(&value) {
+        Ok(()) => app.set_status(format!("Copied {} to clipboard", value)),
+        Err(e) => app.set_error_status(format!("Failed to copy: {}", e)),
+    }
+}
+
 fn handle_error_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
     match key.code {
         // Only quit on Ctrl+C or Esc
@@ -209,6 +875,12 @@ fn handle_error_screen(app: &mut App, key: KeyEvent) -> anyhow::Result<bool> {
         KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Enter | KeyCode::Esc => {
             app.reset();
         }
+        // Return to the view a failed quick search or drill-down started from
+        KeyCode::Backspace => {
+            if !app.pop_nav() {
+                app.reset();
+            }
+        }
         _ => {}
     }
     Ok(false)
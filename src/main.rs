@@ -1,12 +1,16 @@
 mod app;
+mod cli;
+mod config;
 mod events;
 mod solana;
 mod ui;
 mod utils;
 
-use app::App;
+use app::{App, InputType};
+use cli::Cli;
+use config::Config;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -15,29 +19,146 @@ use ratatui::{
     Terminal,
 };
 use std::io;
+use std::path::Path;
+use std::process::Command;
 
 fn main() -> anyhow::Result<()> {
+    let cli = match Cli::parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if cli.show_help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
+    if let Err(e) = solana::labels::load(&cli.label_files) {
+        eprintln!("error: {:#}", e);
+        std::process::exit(1);
+    }
+
+    // A broken config file shouldn't keep the app from starting, but it also shouldn't be
+    // overwritten on exit, so the user can still fix it by hand
+    let (config, save_config) = match Config::load() {
+        Ok(config) => (config, true),
+        Err(e) => {
+            eprintln!("warning: {:#}; using the default settings", e);
+            (Config::default(), false)
+        }
+    };
+
+    if cli.json {
+        if let Err(e) = print_json(&cli, &config) {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    install_panic_hook();
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run_app(&mut terminal);
+    let result = run_app(&mut terminal, &cli, &config);
 
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
-    result
+    // Losing the saved settings isn't worth a failing exit status
+    let config = result?;
+    if save_config {
+        if let Err(e) = config.save() {
+            eprintln!("warning: {:#}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Restores the terminal before the default hook prints the panic, so a crash doesn't leave
+/// the shell stuck in raw mode on the alternate screen. When panics unwind, a fetch thread's
+/// panic leaves the TUI running, so instead of printing over it the message is kept for the
+/// fetch's error report. Release builds abort on panic, so there every panic tears down.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if cfg!(panic = "unwind") && std::thread::current().name() != Some("main") {
+            let payload = info.payload();
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            if let Ok(mut panic) = app::WORKER_PANIC.lock() {
+                *panic = Some(message);
+            }
+            return;
+        }
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableBracketedPaste,
+            crossterm::cursor::Show
+        );
+        default_hook(info);
+    }));
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> anyhow::Result<()> {
+/// Fetches the requested item and prints it as JSON without entering the TUI.
+fn print_json(cli: &Cli, config: &Config) -> anyhow::Result<()> {
     let mut app = App::new();
+    config.apply(&mut app);
+    cli.apply(&mut app);
+
+    let json = match app.get_input_type() {
+        InputType::Account => {
+            serde_json::to_string_pretty(&app.client().fetch_account(&app.input)?)?
+        }
+        InputType::Transaction => {
+            serde_json::to_string_pretty(&app.client().fetch_transaction(&app.input)?)?
+        }
+        InputType::Batch => {
+            anyhow::bail!("JSON output takes a single signature or address, not a list")
+        }
+        InputType::Unknown => {
+            anyhow::bail!("'{}' is not a valid signature or address", app.input)
+        }
+    };
+
+    println!("{}", json);
+    Ok(())
+}
+
+fn run_app<B: Backend>(
+    terminal: &mut Terminal<B>,
+    cli: &Cli,
+    config: &Config,
+) -> anyhow::Result<Config> {
+    let mut app = App::new();
+    config.apply(&mut app);
+    cli.apply(&mut app);
+    if cli.target.is_some() {
+        events::submit_query(&mut app)?;
+    }
     
     loop {
         terminal.draw(|f| ui::draw(f, &app))?;
@@ -45,7 +166,60 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> anyhow::Result<()> {
         if events::handle_event(&mut app)? {
             break;
         }
+        app.poll_fetch();
+        events::auto_refresh(&mut app);
+        app.advance_loading_tick();
+
+        if let Some(path) = app.pending_editor.take() {
+            open_in_editor(terminal, &mut app, &path)?;
+        }
     }
     
+    Ok(Config::from_app(&app))
+}
+
+/// Suspends the TUI, runs `$EDITOR` on `path`, and restores the terminal whatever the
+/// editor's outcome.
+fn open_in_editor<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    path: &Path,
+) -> anyhow::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
+
+    let status = editor_command(path).status();
+
+    enable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
+    terminal.clear()?;
+
+    match status {
+        Ok(status) if status.success() => app.set_status(format!("Closed {}", path.display())),
+        Ok(status) => app.set_error_status(format!("Editor exited with {}", status)),
+        Err(e) => app.set_error_status(format!("Failed to launch editor: {}", e)),
+    }
+
     Ok(())
 }
+
+fn editor_command(path: &Path) -> Command {
+    let default = if cfg!(windows) { "notepad" } else { "vi" };
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| default.to_string());
+
+    // $EDITOR may carry arguments, e.g. "code --wait"
+    let mut parts = editor.split_whitespace();
+    let mut command = Command::new(parts.next().unwrap_or(default));
+    command.args(parts).arg(path);
+    command
+}
@@ -1,39 +1,91 @@
+use crate::solana::errors;
+use crate::solana::labels::builtin_program_name;
+use crate::solana::summary;
 use crate::solana::types::*;
-use crate::solana::Network;
+use crate::solana::{Commitment, Network};
 use anyhow::Result;
-use solana_client::rpc_client::RpcClient;
-use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_account_decoder::{UiAccount, UiAccountData, UiAccountEncoding};
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient};
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_client::rpc_request::RpcRequest;
+use solana_client::rpc_response::Response;
+use solana_sdk::{pubkey::Pubkey, signature::Signature, transaction::TransactionError};
 use solana_transaction_status::{
     option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
-    UiCompiledInstruction, UiInstruction, UiParsedInstruction, UiTransactionEncoding,
+    TransactionVersion, UiCompiledInstruction, UiInstruction, UiLoadedAddresses,
+    UiParsedInstruction, UiTransactionEncoding, UiTransactionStatusMeta, UiTransactionTokenBalance,
 };
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
 
 pub struct SolanaClient {
     client: RpcClient,
     network: Network,
+    commitment: Commitment,
+    max_supported_transaction_version: Option<u8>,
+    /// Fetch each history entry's transaction to fill in its fee
+    history_fees: bool,
+    /// Resolved token names by mint; `None` records a mint with no name to find
+    token_names: Mutex<HashMap<Pubkey, Option<String>>>,
 }
 
 impl std::fmt::Debug for SolanaClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SolanaClient")
             .field("network", &self.network)
+            .field("commitment", &self.commitment)
+            .field(
+                "max_supported_transaction_version",
+                &self.max_supported_transaction_version,
+            )
             .finish_non_exhaustive()
     }
 }
 
 impl SolanaClient {
-    pub fn new(network: Network) -> Self {
-        let client = RpcClient::new_with_commitment(
-            network.url().to_string(),
-            CommitmentConfig::confirmed(),
+    pub fn new(network: Network, commitment: Commitment) -> Self {
+        let client = RpcClient::new_with_commitment(network.url().to_string(), commitment.config());
+        Self {
+            client,
+            network,
+            commitment,
+            max_supported_transaction_version: Some(0),
+            history_fees: false,
+            token_names: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Rebuilds the underlying RPC client with a per-request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client = RpcClient::new_with_timeout_and_commitment(
+            self.network.url().to_string(),
+            timeout,
+            self.commitment.config(),
         );
-        Self { client, network }
+        self
+    }
+
+    /// `None` requests legacy transactions only, mirroring wallets without v0 support.
+    pub fn with_max_supported_transaction_version(mut self, version: Option<u8>) -> Self {
+        self.max_supported_transaction_version = version;
+        self
+    }
+
+    /// Costs one extra `getTransaction` call per history entry.
+    pub fn with_history_fees(mut self, enabled: bool) -> Self {
+        self.history_fees = enabled;
+        self
     }
 
-    #[allow(dead_code)]
     pub fn network(&self) -> Network {
-        self.network
+        self.network.clone()
+    }
+
+    pub fn commitment(&self) -> Commitment {
+        self.commitment
     }
 
     pub fn fetch_transaction(&self, signature_str: &str) -> Result<TransactionData> {
@@ -41,106 +93,301 @@ impl SolanaClient {
 
         let config = solana_client::rpc_config::RpcTransactionConfig {
             encoding: Some(UiTransactionEncoding::JsonParsed),
-            commitment: Some(CommitmentConfig::confirmed()),
-            max_supported_transaction_version: Some(0),
+            commitment: Some(self.transaction_commitment().config()),
+            max_supported_transaction_version: self.max_supported_transaction_version,
         };
 
-        let txn = self
-            .client
-            .get_transaction_with_config(&signature, config)?;
+        let txn = with_retry(|| self.client.get_transaction_with_config(&signature, config))
+            .map_err(|e| {
+                if e.to_string()
+                    .contains("is not supported by the requesting client")
+                {
+                    self.unsupported_version_error()
+                } else {
+                    e
+                }
+            })?;
+
+        if let Some(TransactionVersion::Number(version)) = &txn.transaction.version {
+            if self
+                .max_supported_transaction_version
+                .is_none_or(|max| *version > max)
+            {
+                return Err(self.unsupported_version_error());
+            }
+        }
+
+        let lookup_table_addresses = address_table_lookups(&txn);
+        let mut data = self.parse_transaction(txn, signature)?;
+        self.apply_transfer_fees(&mut data.token_transfers, data.slot);
+        let names = self.resolve_token_names(data.token_transfers.iter().map(|t| t.mint));
+        for transfer in &mut data.token_transfers {
+            transfer.token_name = names.get(&transfer.mint).cloned();
+        }
+        data.lookup_tables = lookup_table_addresses
+            .into_iter()
+            .map(|address| LookupTableInfo {
+                address,
+                status: self.lookup_table_status(&address),
+            })
+            .collect();
+        data.summary = summary::describe(&data);
+        Ok(data)
+    }
+
+    /// getTransaction and getSignaturesForAddress reject anything below confirmed.
+    fn transaction_commitment(&self) -> Commitment {
+        match self.commitment {
+            Commitment::Processed => Commitment::Confirmed,
+            commitment => commitment,
+        }
+    }
 
-        self.parse_transaction(txn, signature)
+    fn unsupported_version_error(&self) -> anyhow::Error {
+        let configured = match self.max_supported_transaction_version {
+            Some(max) => format!("v{}", max),
+            None => "legacy only".to_string(),
+        };
+        anyhow::anyhow!(
+            "Transaction version is newer than the configured maximum ({}). \
+             Re-run with a higher --max-tx-version to view it.",
+            configured
+        )
     }
 
     pub fn fetch_account(&self, address_str: &str) -> Result<AccountData> {
         let pubkey = Pubkey::from_str(address_str)?;
 
-        let account = self.client.get_account(&pubkey)?;
+        // A never-funded or closed address has no account but may still have signature
+        // history, so only a failed request is an error
+        let account = with_retry(|| {
+            self.client
+                .get_account_with_commitment(&pubkey, self.client.commitment())
+        })
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "RPC error while looking up the account on {}: {}",
+                self.network.name(),
+                e
+            )
+        })?
+        .value;
+        let exists = account.is_some();
+        let account = account.unwrap_or_default();
 
         let token_accounts = self.fetch_token_accounts(&pubkey)?;
 
-        let signatures = self.client.get_signatures_for_address(&pubkey)?;
-        let recent_transactions = signatures
-            .into_iter()
-            .take(10)
-            .map(|sig| TransactionSummary {
-                signature: Signature::from_str(&sig.signature).unwrap_or_default(),
-                slot: sig.slot,
-                timestamp: sig
-                    .block_time
-                    .and_then(|t| chrono::DateTime::from_timestamp(t, 0)),
-                status: if let Some(err) = sig.err {
-                    TransactionStatus::Failed(format!("{:?}", err))
-                } else {
-                    TransactionStatus::Success
-                },
-                fee: 0,
-                description: String::new(),
-            })
-            .collect();
+        let min_balance_for_rent_exemption = if exists {
+            self.client
+                .get_minimum_balance_for_rent_exemption(account.data.len())
+                .ok()
+        } else {
+            None
+        };
+
+        let vote_state = if builtin_program_name(&account.owner) == Some("Vote Program") {
+            self.fetch_parsed_account(&pubkey)
+                .ok()
+                .flatten()
+                .and_then(|parsed| parse_vote_state(&parsed))
+        } else {
+            None
+        };
+
+        let stake_state = if builtin_program_name(&account.owner) == Some("Stake Program") {
+            let current_epoch = self.client.get_epoch_info().ok().map(|info| info.epoch);
+            self.fetch_parsed_account(&pubkey)
+                .ok()
+                .flatten()
+                .and_then(|parsed| parse_stake_state(&parsed, current_epoch))
+        } else {
+            None
+        };
+
+        let account_type = classify_account(exists, &account);
+        let mint_state = if account_type.starts_with("Token Mint") {
+            self.fetch_parsed_account(&pubkey)
+                .ok()
+                .flatten()
+                .and_then(|parsed| parse_mint_state(&parsed))
+                .map(|mut mint| {
+                    // Token-2022 metadata stored on the mint itself is the fallback name
+                    if let Some(name) = self.resolve_token_names([pubkey]).remove(&pubkey) {
+                        mint.token_name = Some(name);
+                    }
+                    mint
+                })
+        } else {
+            None
+        };
+
+        let recent_transactions = self.fetch_history(&pubkey, None, HISTORY_PAGE_SIZE)?;
+        let history_before = next_history_cursor(&recent_transactions, HISTORY_PAGE_SIZE);
 
         Ok(AccountData {
             pubkey,
+            exists,
             lamports: account.lamports,
             owner: account.owner,
             executable: account.executable,
             rent_epoch: account.rent_epoch,
             data_size: account.data.len(),
+            data: account.data,
             token_accounts,
             recent_transactions,
-            account_type: String::new(),
-            is_rent_exempt: false,
-            min_balance_for_rent_exemption: None,
+            account_type,
+            is_rent_exempt: min_balance_for_rent_exemption
+                .is_some_and(|min| account.lamports >= min),
+            min_balance_for_rent_exemption,
+            vote_state,
+            stake_state,
+            mint_state,
+            history_before,
+            history_page_size: HISTORY_PAGE_SIZE,
         })
     }
 
+    /// Up to `limit` signatures for `pubkey`, newest first, starting just before `before`.
+    pub fn fetch_history(
+        &self,
+        pubkey: &Pubkey,
+        before: Option<Signature>,
+        limit: usize,
+    ) -> Result<Vec<TransactionSummary>> {
+        let signatures = with_retry(|| {
+            let config = GetConfirmedSignaturesForAddress2Config {
+                before,
+                until: None,
+                limit: Some(limit),
+                commitment: Some(self.transaction_commitment().config()),
+            };
+            self.client
+                .get_signatures_for_address_with_config(pubkey, config)
+        })?;
+
+        signatures
+            .into_iter()
+            .map(|sig| {
+                let signature = history_signature(&sig.signature)?;
+                Ok(TransactionSummary {
+                    signature,
+                    slot: sig.slot,
+                    timestamp: sig
+                        .block_time
+                        .and_then(|t| chrono::DateTime::from_timestamp(t, 0)),
+                    status: if let Some(err) = sig.err {
+                        TransactionStatus::Failed(format!("{:?}", err))
+                    } else {
+                        TransactionStatus::Success
+                    },
+                    fee: if self.history_fees {
+                        self.transaction_fee(&signature).unwrap_or(0)
+                    } else {
+                        0
+                    },
+                    description: sig.memo.as_deref().map(memo_text).unwrap_or_default(),
+                })
+            })
+            .collect()
+    }
+
+    /// Fee paid by a transaction, fetched without any parsing.
+    fn transaction_fee(&self, signature: &Signature) -> Option<u64> {
+        let config = solana_client::rpc_config::RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(self.transaction_commitment().config()),
+            max_supported_transaction_version: self.max_supported_transaction_version,
+        };
+        let txn = self
+            .client
+            .get_transaction_with_config(signature, config)
+            .ok()?;
+        txn.transaction.meta.map(|meta| meta.fee)
+    }
+
     fn fetch_token_accounts(&self, owner: &Pubkey) -> Result<Vec<TokenAccountInfo>> {
-        let token_accounts = self.client.get_token_accounts_by_owner(
-            owner,
-            solana_client::rpc_request::TokenAccountsFilter::ProgramId(
-                solana_sdk::pubkey::Pubkey::from_str(
-                    "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
-                )?,
-            ),
-        )?;
+        let token_program =
+            solana_sdk::pubkey::Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")?;
+        let token_accounts = with_retry(|| {
+            self.client.get_token_accounts_by_owner(
+                owner,
+                solana_client::rpc_request::TokenAccountsFilter::ProgramId(token_program),
+            )
+        })?;
+
+        let mut result: Vec<TokenAccountInfo> = token_accounts
+            .into_iter()
+            .filter_map(|acc| match acc.account.data {
+                solana_account_decoder::UiAccountData::Json(parsed) => {
+                    token_account_info(&parsed.parsed)
+                }
+                _ => None,
+            })
+            .collect();
 
-        let mut result = Vec::new();
-        for acc in token_accounts {
-            if let solana_account_decoder::UiAccountData::Json(parsed) = acc.account.data {
-                if let Ok(parsed_acc) = serde_json::from_value::<serde_json::Value>(parsed.parsed) {
-                    if let Some(info) = parsed_acc.get("info") {
-                        if let (Some(mint), Some(amount)) = (
-                            info.get("mint").and_then(|m| m.as_str()),
-                            info.get("tokenAmount")
-                                .and_then(|t: &serde_json::Value| t.get("amount"))
-                                .and_then(|a: &serde_json::Value| a.as_str()),
-                        ) {
-                            let decimals = info
-                                .get("tokenAmount")
-                                .and_then(|t: &serde_json::Value| t.get("decimals"))
-                                .and_then(|d: &serde_json::Value| d.as_u64())
-                                .unwrap_or(0) as u8;
-
-                            let ui_amount = info
-                                .get("tokenAmount")
-                                .and_then(|t: &serde_json::Value| t.get("uiAmount"))
-                                .and_then(|u| u.as_f64())
-                                .unwrap_or(0.0);
-
-                            result.push(TokenAccountInfo {
-                                mint: Pubkey::from_str(mint).unwrap_or_default(),
-                                amount: amount.parse::<u64>().unwrap_or(0),
-                                decimals,
-                                token_name: None,
-                                ui_amount,
-                            });
-                        }
+        let names = self.resolve_token_names(result.iter().map(|t| t.mint));
+        for token in &mut result {
+            token.token_name = names.get(&token.mint).cloned();
+        }
+
+        Ok(result)
+    }
+
+    /// Display names (symbol, else name) for `mints`: well-known mints come from a bundled
+    /// list, the rest from their Metaplex metadata accounts. Lookups are cached, misses
+    /// included; a failed RPC request is not, so it's retried on the next fetch.
+    fn resolve_token_names(
+        &self,
+        mints: impl IntoIterator<Item = Pubkey>,
+    ) -> HashMap<Pubkey, String> {
+        let mints: Vec<Pubkey> = mints.into_iter().collect();
+        // Never held across a request, so other fetches can read the cache meanwhile
+        let cache = || self.token_names.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut unresolved = Vec::new();
+        {
+            let mut cache = cache();
+            for mint in &mints {
+                if cache.contains_key(mint) || unresolved.contains(mint) {
+                    continue;
+                }
+                match well_known_token(mint) {
+                    Some(symbol) => {
+                        cache.insert(*mint, Some(symbol.to_string()));
                     }
+                    None => unresolved.push(*mint),
                 }
             }
         }
 
-        Ok(result)
+        let metadata_program = TOKEN_METADATA_PROGRAM;
+        for chunk in unresolved.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let pdas: Vec<Pubkey> = chunk
+                .iter()
+                .map(|mint| {
+                    let seeds = [
+                        b"metadata".as_ref(),
+                        metadata_program.as_ref(),
+                        mint.as_ref(),
+                    ];
+                    Pubkey::find_program_address(&seeds, &metadata_program).0
+                })
+                .collect();
+            let Ok(accounts) = self.client.get_multiple_accounts(&pdas) else {
+                continue;
+            };
+            let names = chunk.iter().zip(accounts).map(|(mint, account)| {
+                let name = account.and_then(|account| metadata_token_name(&account.data));
+                (*mint, name)
+            });
+            cache().extend(names);
+        }
+
+        let cache = cache();
+        mints
+            .into_iter()
+            .filter_map(|mint| Some((mint, cache.get(&mint)?.clone()?)))
+            .collect()
     }
 
     fn parse_transaction(
@@ -158,14 +405,16 @@ impl SolanaClient {
             .block_time
             .and_then(|t| chrono::DateTime::from_timestamp(t, 0));
 
-        let status = if let Some(err) = meta.err {
-            TransactionStatus::Failed(format!("{:?}", err))
-        } else {
-            TransactionStatus::Success
+        // Newer RPC clients wrap the error for serialization; normalize it
+        let error: Option<TransactionError> = meta.err.clone().map(Into::into);
+        let status = match &error {
+            Some(err) => TransactionStatus::Failed(format!("{:?}", err)),
+            None => TransactionStatus::Success,
         };
 
         // Get account keys for mapping indices to pubkeys
-        let account_keys = match &txn.transaction.transaction {
+        let loaded = loaded_addresses(&meta.loaded_addresses);
+        let mut account_keys = match &txn.transaction.transaction {
             solana_transaction_status::EncodedTransaction::Json(parsed_txn) => {
                 match &parsed_txn.message {
                     solana_transaction_status::UiMessage::Raw(raw_msg) => raw_msg
@@ -182,32 +431,52 @@ impl SolanaClient {
             }
             _ => Vec::new(),
         };
+        // Raw messages list only the static keys; lookup table accounts follow them
+        for pubkey in loaded.writable.iter().chain(&loaded.readonly) {
+            if !account_keys.contains(pubkey) {
+                account_keys.push(*pubkey);
+            }
+        }
 
         // Parse instructions from the transaction
-        let instructions = self.parse_instructions(&txn, &account_keys)?;
+        let mut instructions = self.parse_instructions(&txn, &account_keys)?;
+        let logs: &[String] = match &meta.log_messages {
+            OptionSerializer::Some(logs) => logs,
+            _ => &[],
+        };
+        let failure_reason = error
+            .as_ref()
+            .map(|err| errors::describe(err, &instructions, logs));
 
         // Parse inner instructions
         let inner_ix_option = match meta.inner_instructions.clone() {
             OptionSerializer::Some(ixs) => Some(ixs),
             _ => None,
         };
-        let _inner_instructions = self.parse_inner_instructions(&inner_ix_option, &account_keys);
+        let mut inner_instructions =
+            self.parse_inner_instructions(&inner_ix_option, &account_keys, instructions.len());
+        let instruction_accounts = instructions
+            .iter_mut()
+            .chain(inner_instructions.iter_mut().flatten())
+            .flat_map(|instruction| instruction.accounts.iter_mut());
+        for account in instruction_accounts {
+            if loaded.contains(&account.pubkey) {
+                account.source = AccountSource::Lookup;
+            }
+        }
 
-        // Parse token transfers from logs
-        let logs_option = match meta.log_messages.clone() {
-            OptionSerializer::Some(logs) => Some(logs),
-            _ => None,
-        };
-        let token_transfers = self.parse_token_transfers_from_logs(&logs_option, &account_keys);
+        let token_transfers =
+            self.parse_token_transfers(outer_instructions(&txn), &meta, &account_keys);
 
         // Parse SOL transfers from system program instructions
         let sol_transfers = self.parse_sol_transfers(&instructions, &account_keys);
 
         // Calculate priority fees from compute budget instructions
         let priority_fee = self.calculate_priority_fee(&instructions);
+        let loaded_accounts_data_size_limit = self.loaded_accounts_data_size_limit(&instructions);
 
         // Extract accounts from the transaction message
-        let accounts = match &txn.transaction.transaction {
+        let mut accounts = match &txn.transaction.transaction {
             solana_transaction_status::EncodedTransaction::Json(parsed_txn) => {
                 match &parsed_txn.message {
                     solana_transaction_status::UiMessage::Raw(raw_msg) => {
@@ -244,6 +513,7 @@ impl SolanaClient {
                                     pre_balance,
                                     post_balance,
                                     account_type: None,
+                                    source: AccountSource::Static,
                                 })
                             })
                             .collect()
@@ -260,6 +530,20 @@ impl SolanaClient {
                                 let pre_balance = meta.pre_balances.get(idx).copied();
                                 let post_balance = meta.post_balances.get(idx).copied();
 
+                                // Lookup table accounts can never sign, and their
+                                // writability is fixed by which list loaded them
+                                if loaded.contains(&pubkey) {
+                                    return Some(AccountMeta {
+                                        pubkey,
+                                        is_signer: false,
+                                        is_writable: loaded.writable.contains(&pubkey),
+                                        pre_balance,
+                                        post_balance,
+                                        account_type: None,
+                                        source: AccountSource::Lookup,
+                                    });
+                                }
+
                                 Some(AccountMeta {
                                     pubkey,
                                     is_signer: parsed_acc.signer,
@@ -267,6 +551,7 @@ impl SolanaClient {
                                     pre_balance,
                                     post_balance,
                                     account_type: None,
+                                    source: AccountSource::Static,
                                 })
                             })
                             .collect()
@@ -275,27 +560,32 @@ impl SolanaClient {
             }
             _ => Vec::new(),
         };
+        append_loaded_accounts(&mut accounts, &loaded, &meta);
+
+        let balance_warning = balance_length_warning(
+            accounts.len(),
+            meta.pre_balances.len(),
+            meta.post_balances.len(),
+        );
 
         // Get max compute units from compute budget instructions
-        let max_compute_units = instructions
-            .iter()
-            .filter(|i| get_program_name(&i.program_id) == Some("Compute Budget"))
-            .filter_map(|i| {
-                if i.instruction_type.contains("SetComputeUnitLimit") {
-                    i.data.parse::<u64>().ok()
-                } else {
-                    None
-                }
-            })
-            .next();
+        let max_compute_units = instructions.iter().find_map(|i| match i.compute_budget() {
+            Some(ComputeBudgetInstruction::SetComputeUnitLimit(units)) => Some(units as u64),
+            _ => None,
+        });
 
         Ok(TransactionData {
             signature,
+            // Filled in once token names are resolved
+            summary: String::new(),
+            signatures: transaction_signatures(&txn),
             slot: txn.slot,
+            commitment: self.transaction_commitment(),
             block_time,
             fee: meta.fee,
             status,
             instructions,
+            inner_instructions,
             accounts,
             logs: match meta.log_messages {
                 OptionSerializer::Some(logs) => logs,
@@ -305,11 +595,19 @@ impl SolanaClient {
                 OptionSerializer::Some(units) => Some(units),
                 _ => None,
             },
-            version: txn.transaction.version.map(|v| format!("{:?}", v)),
+            version: txn.transaction.version.map(|version| match version {
+                TransactionVersion::Legacy(_) => "legacy".to_string(),
+                TransactionVersion::Number(n) => format!("v{}", n),
+            }),
             token_transfers,
             sol_transfers,
             priority_fee,
             max_compute_units,
+            loaded_accounts_data_size_limit,
+            lookup_tables: Vec::new(),
+            loaded_addresses: loaded,
+            balance_warning,
+            failure_reason,
         })
     }
 
@@ -334,7 +632,7 @@ impl SolanaClient {
                         for (idx, ui_instr) in parsed_msg.instructions.iter().enumerate() {
                             let instruction = match ui_instr {
                                 UiInstruction::Parsed(parsed) => {
-                                    self.parse_parsed_instruction(parsed, idx)
+                                    self.parse_parsed_instruction(parsed, idx)?
                                 }
                                 UiInstruction::Compiled(compiled) => {
                                     // Should not happen in parsed message usually, but fallback
@@ -344,6 +642,7 @@ impl SolanaClient {
                                             program_name: None,
                                             instruction_type: "Unknown (Compiled)".to_string(),
                                             data: compiled.data.clone(),
+                                            raw_data: bs58::decode(&compiled.data).into_vec().ok(),
                                             accounts: Vec::new(),
                                             compute_units_consumed: None,
                                         })
@@ -388,20 +687,18 @@ impl SolanaClient {
                         pre_balance: None,
                         post_balance: None,
                         account_type: None,
+                        source: AccountSource::Static,
                     })
             })
             .collect();
 
         // Try to decode base58 data
-        let data_str = if let Ok(decoded) = bs58::decode(&ui_instr.data).into_vec() {
-            let decoded: Vec<u8> = decoded;
-            if decoded.len() >= 1 {
+        let raw_data = bs58::decode(&ui_instr.data).into_vec().ok();
+        let data_str = match &raw_data {
+            Some(decoded) if !decoded.is_empty() => {
                 format!("{} ({} bytes)", ui_instr.data, decoded.len())
-            } else {
-                ui_instr.data.clone()
             }
-        } else {
-            ui_instr.data.clone()
+            _ => ui_instr.data.clone(),
         };
 
         Ok(InstructionInfo {
@@ -409,6 +706,7 @@ impl SolanaClient {
             program_name,
             instruction_type,
             data: data_str,
+            raw_data,
             accounts,
             compute_units_consumed: None,
         })
@@ -418,10 +716,15 @@ impl SolanaClient {
         &self,
         ui_instr: &UiParsedInstruction,
         _idx: usize,
-    ) -> InstructionInfo {
+    ) -> Result<InstructionInfo> {
+        // The default pubkey is the System Program, so a bad id can't fall back to it
+        let parse_program_id = |program_id: &str| {
+            Pubkey::from_str(program_id)
+                .map_err(|_| anyhow::anyhow!("Invalid program id '{}'", program_id))
+        };
         match ui_instr {
             UiParsedInstruction::Parsed(parsed) => {
-                let program_id = Pubkey::from_str(&parsed.program_id).unwrap_or_default();
+                let program_id = parse_program_id(&parsed.program_id)?;
                 let program_name = get_program_name(&program_id).map(|s| s.to_string());
 
                 let (instruction_type, data) = if let Ok(parsed_value) =
@@ -435,6 +738,9 @@ impl SolanaClient {
                             .map(|i| i.to_string())
                             .unwrap_or_default();
                         (instruction_type.to_string(), data)
+                    } else if let Some(memo) = parsed_value.as_str() {
+                        // The Memo parser returns the text itself rather than an object
+                        ("Memo".to_string(), memo.to_string())
                     } else {
                         ("Unknown".to_string(), parsed.parsed.to_string())
                     }
@@ -462,6 +768,7 @@ impl SolanaClient {
                                                     pre_balance: None,
                                                     post_balance: None,
                                                     account_type: Some(key.clone()),
+                                                    source: AccountSource::Static,
                                                 }
                                             })
                                         } else {
@@ -478,17 +785,18 @@ impl SolanaClient {
                     Vec::new()
                 };
 
-                InstructionInfo {
+                Ok(InstructionInfo {
                     program_id,
                     program_name,
                     instruction_type,
                     data,
+                    raw_data: None,
                     accounts,
                     compute_units_consumed: None,
-                }
+                })
             }
             UiParsedInstruction::PartiallyDecoded(partial) => {
-                let program_id = Pubkey::from_str(&partial.program_id).unwrap_or_default();
+                let program_id = parse_program_id(&partial.program_id)?;
                 let program_name = get_program_name(&program_id).map(|s| s.to_string());
 
                 let accounts: Vec<AccountMeta> = partial
@@ -502,24 +810,33 @@ impl SolanaClient {
                             pre_balance: None,
                             post_balance: None,
                             account_type: None,
+                            source: AccountSource::Static,
                         })
                     })
                     .collect();
 
-                InstructionInfo {
+                // The RPC has no parser for e.g. Compute Budget, but we can still name it
+                let instruction_type =
+                    match self.identify_instruction_type(&program_id, &partial.data) {
+                        t if t == "Unknown" => "PartiallyDecoded".to_string(),
+                        t => t,
+                    };
+
+                Ok(InstructionInfo {
                     program_id,
                     program_name,
-                    instruction_type: "PartiallyDecoded".to_string(),
+                    instruction_type,
                     data: partial.data.clone(),
+                    raw_data: bs58::decode(&partial.data).into_vec().ok(),
                     accounts,
                     compute_units_consumed: None,
-                }
+                })
             }
         }
     }
 
     fn identify_instruction_type(&self, program_id: &Pubkey, data: &str) -> String {
-        let program_name = get_program_name(program_id);
+        let program_name = builtin_program_name(program_id);
 
         match program_name {
             Some("System Program") => {
@@ -606,6 +923,11 @@ impl SolanaClient {
                     "Unknown".to_string()
                 }
             }
+            Some("Memo Program") | Some("Memo Program (v1)") => "Memo".to_string(),
+            Some("Stake Program") => bs58::decode(data)
+                .into_vec()
+                .map(|decoded| stake_instruction_type(&decoded))
+                .unwrap_or_else(|_| "Unknown".to_string()),
             _ => "Unknown".to_string(),
         }
     }
@@ -614,23 +936,28 @@ impl SolanaClient {
         &self,
         inner_instructions: &Option<Vec<solana_transaction_status::UiInnerInstructions>>,
         account_keys: &[Pubkey],
-    ) -> Vec<InstructionInfo> {
-        let mut result = Vec::new();
+        outer_count: usize,
+    ) -> Vec<Vec<InstructionInfo>> {
+        let mut result = vec![Vec::new(); outer_count];
 
         if let Some(inner_ixs) = inner_instructions {
             for inner in inner_ixs {
+                let Some(calls) = result.get_mut(inner.index as usize) else {
+                    continue;
+                };
                 for (idx, ui_instr) in inner.instructions.iter().enumerate() {
                     match ui_instr {
                         UiInstruction::Compiled(compiled) => {
                             if let Ok(instruction) =
                                 self.parse_raw_instruction(compiled, account_keys, idx)
                             {
-                                result.push(instruction);
+                                calls.push(instruction);
                             }
                         }
                         UiInstruction::Parsed(parsed) => {
-                            let instruction = self.parse_parsed_instruction(parsed, idx);
-                            result.push(instruction);
+                            if let Ok(instruction) = self.parse_parsed_instruction(parsed, idx) {
+                                calls.push(instruction);
+                            }
                         }
                     }
                 }
@@ -640,33 +967,169 @@ impl SolanaClient {
         result
     }
 
-    fn parse_token_transfers_from_logs(
+    /// Token program instructions describe every transfer exactly when they're present;
+    /// balance deltas are the fallback for transactions that move tokens without them.
+    fn parse_token_transfers(
         &self,
-        logs: &Option<Vec<String>>,
+        outer: &[UiInstruction],
+        meta: &UiTransactionStatusMeta,
         account_keys: &[Pubkey],
     ) -> Vec<TokenTransfer> {
-        let mut transfers = Vec::new();
+        let transfers = self.parse_token_transfers_from_instructions(outer, meta, account_keys);
+        if transfers.is_empty() {
+            return self.parse_token_transfers_from_balances(meta, account_keys);
+        }
+        transfers
+    }
 
-        if let Some(log_messages) = logs {
-            for log in log_messages {
-                // Parse TransferChecked events from token program logs
-                if log.contains("Transfer") && log.contains("amount:") {
-                    // Example log: "Program Tokenkeg... invoke [2]"
-                    // "Program log: Instruction: TransferChecked"
-                    // "Program log: TransferChecked 1000 from <from> to <to>, mint <mint>"
-                    // This is simplified parsing - real implementation would be more robust
-
-                    if let Some(transfer) = self.parse_transfer_log(log, account_keys) {
-                        transfers.push(transfer);
-                    }
+    /// Reads transfers, mints and burns from the Token and Token-2022 instructions, outer and
+    /// inner, in execution order. Instructions the RPC couldn't parse are read from their
+    /// data instead, but only when the program's log confirms which instruction ran. Each
+    /// instruction yields at most one transfer, so the two sources never repeat a transfer.
+    fn parse_token_transfers_from_instructions(
+        &self,
+        outer: &[UiInstruction],
+        meta: &UiTransactionStatusMeta,
+        account_keys: &[Pubkey],
+    ) -> Vec<TokenTransfer> {
+        let inner = match &meta.inner_instructions {
+            OptionSerializer::Some(inner) => inner.as_slice(),
+            _ => &[],
+        };
+
+        // Token account -> (mint, decimals), for instructions that don't name the mint
+        let mut token_accounts: HashMap<Pubkey, (Pubkey, u8)> = HashMap::new();
+        for balance in token_balances(&meta.pre_token_balances)
+            .iter()
+            .chain(token_balances(&meta.post_token_balances))
+        {
+            let (Some(account), Ok(mint)) = (
+                account_keys.get(balance.account_index as usize),
+                Pubkey::from_str(&balance.mint),
+            ) else {
+                continue;
+            };
+            token_accounts.insert(*account, (mint, balance.ui_token_amount.decimals));
+        }
+
+        let executed: Vec<&UiInstruction> = outer
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, instruction)| {
+                let invoked = inner
+                    .iter()
+                    .filter(move |set| set.index as usize == idx)
+                    .flat_map(|set| &set.instructions);
+                std::iter::once(instruction).chain(invoked)
+            })
+            .collect();
+        let token_instructions: Vec<&UiInstruction> = executed
+            .into_iter()
+            .filter(|instruction| {
+                instruction_program(instruction, account_keys).is_some_and(|p| is_token_program(&p))
+            })
+            .collect();
+
+        let logs: &[String] = match &meta.log_messages {
+            OptionSerializer::Some(logs) => logs,
+            _ => &[],
+        };
+        let mut logged_names = token_instruction_logs(logs, token_instructions.len()).into_iter();
+
+        let mut transfers = Vec::new();
+        for instruction in token_instructions {
+            let logged = logged_names.next().flatten();
+            let transfer = match instruction {
+                UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => {
+                    parsed_token_transfer(&parsed.program_id, &parsed.parsed, &token_accounts)
                 }
+                _ => logged.and_then(|name| {
+                    log_token_transfer(instruction, name, account_keys, &token_accounts)
+                }),
+            };
+            transfers.extend(transfer);
+        }
 
-                // Parse Token:Transfer events
-                if log.contains("Token:Transfer") {
-                    // Parse Token:Transfer(amount, decimals)
-                    if let Some(transfer) = self.parse_token_transfer_event(log, account_keys) {
-                        transfers.push(transfer);
-                    }
+        transfers
+    }
+
+    fn parse_token_transfers_from_balances(
+        &self,
+        meta: &UiTransactionStatusMeta,
+        account_keys: &[Pubkey],
+    ) -> Vec<TokenTransfer> {
+        // account index -> balance before/after, keyed so the output order is stable
+        let mut balances: BTreeMap<u8, TokenBalanceChange> = BTreeMap::new();
+        for balance in token_balances(&meta.pre_token_balances) {
+            balances
+                .entry(balance.account_index)
+                .or_insert_with(|| TokenBalanceChange::from_balance(balance))
+                .pre = balance.ui_token_amount.amount.parse().unwrap_or(0);
+        }
+        for balance in token_balances(&meta.post_token_balances) {
+            balances
+                .entry(balance.account_index)
+                .or_insert_with(|| TokenBalanceChange::from_balance(balance))
+                .post = balance.ui_token_amount.amount.parse().unwrap_or(0);
+        }
+
+        let mut by_mint: BTreeMap<&str, Vec<(Pubkey, i128)>> = BTreeMap::new();
+        for (idx, change) in &balances {
+            let Some(pubkey) = account_keys.get(*idx as usize) else {
+                continue;
+            };
+            let delta = change.post as i128 - change.pre as i128;
+            if delta != 0 {
+                by_mint
+                    .entry(change.mint.as_str())
+                    .or_default()
+                    .push((*pubkey, delta));
+            }
+        }
+
+        let mut transfers = Vec::new();
+        for (mint, deltas) in by_mint {
+            let Some(change) = balances.values().find(|c| c.mint == mint) else {
+                continue;
+            };
+            let Ok(mint) = Pubkey::from_str(mint) else {
+                continue;
+            };
+
+            let mut senders: Vec<(Pubkey, u64)> = deltas
+                .iter()
+                .filter(|(_, d)| *d < 0)
+                .map(|(k, d)| (*k, d.unsigned_abs() as u64))
+                .collect();
+            let mut receivers: Vec<(Pubkey, u64)> = deltas
+                .iter()
+                .filter(|(_, d)| *d > 0)
+                .map(|(k, d)| (*k, *d as u64))
+                .collect();
+
+            // Pair debits with credits in account order. Whatever a sender has left once the
+            // receivers are exhausted (e.g. a withheld transfer fee) is not a transfer.
+            let (mut si, mut ri) = (0, 0);
+            while si < senders.len() && ri < receivers.len() {
+                let amount = senders[si].1.min(receivers[ri].1);
+                transfers.push(TokenTransfer {
+                    from: senders[si].0,
+                    to: receivers[ri].0,
+                    mint,
+                    amount,
+                    decimals: change.decimals,
+                    token_name: None,
+                    program: change.program.clone(),
+                    fee: None,
+                    source: TransferSource::BalanceChange,
+                });
+                senders[si].1 -= amount;
+                receivers[ri].1 -= amount;
+                if senders[si].1 == 0 {
+                    si += 1;
+                }
+                if receivers[ri].1 == 0 {
+                    ri += 1;
                 }
             }
         }
@@ -674,19 +1137,112 @@ impl SolanaClient {
         transfers
     }
 
-    fn parse_transfer_log(&self, _log: &str, _account_keys: &[Pubkey]) -> Option<TokenTransfer> {
-        // Simplified parsing - in reality, this would parse structured log data
-        // For now, return a placeholder that can be enhanced
-        None
+    /// Token-2022 mints can withhold a fee on every transfer, which only
+    /// `transferCheckedWithFee` states. For the rest, work it out from the mint's fee
+    /// config for the epoch the transaction ran in.
+    fn apply_transfer_fees(&self, transfers: &mut [TokenTransfer], slot: u64) {
+        // Mints and burns move no tokens between holders, so they carry no fee
+        let needs_fee = |t: &TokenTransfer| {
+            t.program == "Token-2022 Program"
+                && t.source == TransferSource::Instruction
+                && t.fee.is_none()
+                && t.from != t.mint
+                && t.to != t.mint
+        };
+        let mut mints: Vec<Pubkey> = transfers
+            .iter()
+            .filter(|t| needs_fee(t))
+            .map(|t| t.mint)
+            .collect();
+        mints.sort();
+        mints.dedup();
+        if mints.is_empty() {
+            return;
+        }
+
+        let Ok(schedule) = with_retry(|| self.client.get_epoch_schedule()) else {
+            return;
+        };
+        let configs = self.fetch_transfer_fee_configs(&mints, schedule.get_epoch(slot));
+        for transfer in transfers.iter_mut().filter(|t| needs_fee(t)) {
+            if let Some(config) = configs.get(&transfer.mint) {
+                transfer.fee = Some(config.fee_for_amount(transfer.amount));
+            }
+        }
     }
 
-    fn parse_token_transfer_event(
+    /// Transfer fee configs of `mints` in force at `epoch`, fetched in one request. Mints
+    /// without the extension are left out.
+    fn fetch_transfer_fee_configs(
         &self,
-        _log: &str,
-        _account_keys: &[Pubkey],
-    ) -> Option<TokenTransfer> {
-        // Parse Token:Transfer events from logs
-        None
+        mints: &[Pubkey],
+        epoch: u64,
+    ) -> HashMap<Pubkey, TransferFeeConfig> {
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::JsonParsed),
+            commitment: Some(self.client.commitment()),
+            ..RpcAccountInfoConfig::default()
+        };
+        let keys: Vec<String> = mints.iter().map(|mint| mint.to_string()).collect();
+        let Ok(response) = with_retry(|| {
+            self.client.send::<Response<Vec<Option<UiAccount>>>>(
+                RpcRequest::GetMultipleAccounts,
+                serde_json::json!([keys, config]),
+            )
+        }) else {
+            return HashMap::new();
+        };
+
+        mints
+            .iter()
+            .zip(response.value)
+            .filter_map(|(mint, account)| match account?.data {
+                UiAccountData::Json(parsed) => {
+                    Some((*mint, transfer_fee_config(&parsed.parsed, epoch)?))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn lookup_table_status(&self, address: &Pubkey) -> LookupTableStatus {
+        match self.fetch_parsed_account(address) {
+            Ok(Some(parsed)) => {
+                // Active tables carry u64::MAX as their deactivation slot
+                let deactivation_slot = parsed
+                    .get("info")
+                    .and_then(|info| info.get("deactivationSlot"))
+                    .and_then(json_u64)
+                    .unwrap_or(u64::MAX);
+                if deactivation_slot == u64::MAX {
+                    LookupTableStatus::Active
+                } else {
+                    LookupTableStatus::Deactivated(deactivation_slot)
+                }
+            }
+            Ok(None) => LookupTableStatus::NotFound,
+            Err(_) => LookupTableStatus::Unavailable,
+        }
+    }
+
+    /// Fetches an account with `jsonParsed` encoding and returns its parsed payload, if the
+    /// RPC node knows how to parse the owning program.
+    fn fetch_parsed_account(&self, pubkey: &Pubkey) -> Result<Option<serde_json::Value>> {
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::JsonParsed),
+            commitment: Some(self.client.commitment()),
+            ..RpcAccountInfoConfig::default()
+        };
+
+        let response: Response<Option<UiAccount>> = self.client.send(
+            RpcRequest::GetAccountInfo,
+            serde_json::json!([pubkey.to_string(), config]),
+        )?;
+
+        Ok(response.value.and_then(|account| match account.data {
+            UiAccountData::Json(parsed) => Some(parsed.parsed),
+            _ => None,
+        }))
     }
 
     fn parse_sol_transfers(
@@ -697,79 +1253,1419 @@ impl SolanaClient {
         let mut transfers = Vec::new();
 
         for instruction in instructions {
-            let program_name = get_program_name(&instruction.program_id);
-
-            // Check for System Program Transfer
-            if program_name == Some("System Program") && instruction.instruction_type == "Transfer"
-            {
-                // Try to extract from and to accounts, and amount from data
-                if instruction.accounts.len() >= 2 {
-                    let from = instruction.accounts[0].pubkey;
-                    let to = instruction.accounts[1].pubkey;
-
-                    // Parse amount from data (base58 encoded)
-                    // System transfer data format: [2, ...amount_bytes]
-                    if let Ok(decoded) =
-                        bs58::decode(&instruction.data.split_whitespace().next().unwrap_or(""))
-                            .into_vec()
-                    {
-                        if decoded.len() >= 12 {
-                            // Skip 4 bytes (instruction type), read 8 bytes for lamports
-                            let amount = u64::from_le_bytes([
-                                decoded[4],
-                                decoded[5],
-                                decoded[6],
-                                decoded[7],
-                                decoded[8],
-                                decoded[9],
-                                decoded[10],
-                                decoded[11],
-                            ]);
-                            transfers.push(SolTransfer { from, to, amount });
-                        }
-                    }
-                }
-            }
+            let program_name = builtin_program_name(&instruction.program_id);
 
-            // Check for Token Program Transfer
-            if (program_name == Some("Token Program") || program_name == Some("Token-2022 Program"))
-                && (instruction.instruction_type == "Transfer"
-                    || instruction.instruction_type == "TransferChecked")
+            // Check for System Program Transfer; parsed instructions name it in camelCase
+            if program_name == Some("System Program")
+                && ["transfer", "transferWithSeed"]
+                    .iter()
+                    .any(|kind| instruction.instruction_type.eq_ignore_ascii_case(kind))
             {
-                // These are token transfers, already handled by parse_token_transfers_from_logs
-                // But we could extract more details here if needed
+                let transfer = match &instruction.raw_data {
+                    Some(raw) => raw_sol_transfer(instruction, raw),
+                    // Parsed instructions carry the JSON `info` object as their data
+                    None => parsed_sol_transfer(&instruction.data),
+                };
+                transfers.extend(transfer);
             }
         }
 
         transfers
     }
 
+    /// Price set by a `SetComputeUnitPrice` instruction, in micro-lamports per CU.
     fn calculate_priority_fee(&self, instructions: &[InstructionInfo]) -> Option<u64> {
-        let mut priority_fee = None;
+        instructions.iter().find_map(|i| match i.compute_budget() {
+            Some(ComputeBudgetInstruction::SetComputeUnitPrice(price)) => Some(price),
+            _ => None,
+        })
+    }
 
-        for instruction in instructions {
-            let program_name = get_program_name(&instruction.program_id);
-
-            if program_name == Some("Compute Budget") {
-                // SetComputeUnitPrice instruction: data format [3, ...micro_lamports_bytes]
-                if instruction.instruction_type == "SetComputeUnitPrice" {
-                    if let Ok(decoded) =
-                        bs58::decode(&instruction.data.split_whitespace().next().unwrap_or(""))
-                            .into_vec()
-                    {
-                        if decoded.len() >= 9 {
-                            // Skip 1 byte (instruction type), read 8 bytes for micro_lamports
-                            let micro_lamports = u64::from_le_bytes([
-                                decoded[1], decoded[2], decoded[3], decoded[4], decoded[5],
-                                decoded[6], decoded[7], decoded[8],
-                            ]);
-                            priority_fee = Some(micro_lamports);
-                        }
-                    }
+    /// Value of a `SetLoadedAccountsDataSizeLimit` instruction.
+    fn loaded_accounts_data_size_limit(&self, instructions: &[InstructionInfo]) -> Option<u32> {
+        instructions.iter().find_map(|i| match i.compute_budget() {
+            Some(ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit(bytes)) => Some(bytes),
+            _ => None,
+        })
+    }
+}
+
+/// Lookup table addresses referenced by a v0 transaction's message.
+fn address_table_lookups(txn: &EncodedConfirmedTransactionWithStatusMeta) -> Vec<Pubkey> {
+    let lookups = match &txn.transaction.transaction {
+        solana_transaction_status::EncodedTransaction::Json(parsed_txn) => {
+            match &parsed_txn.message {
+                solana_transaction_status::UiMessage::Raw(raw_msg) => {
+                    raw_msg.address_table_lookups.as_ref()
+                }
+                solana_transaction_status::UiMessage::Parsed(parsed_msg) => {
+                    parsed_msg.address_table_lookups.as_ref()
                 }
             }
         }
+        _ => None,
+    };
+
+    lookups
+        .into_iter()
+        .flatten()
+        .filter_map(|lookup| Pubkey::from_str(&lookup.account_key).ok())
+        .collect()
+}
+
+fn history_signature(signature: &str) -> Result<Signature> {
+    Signature::from_str(signature)
+        .map_err(|_| anyhow::anyhow!("RPC returned an invalid signature '{}'", signature))
+}
+
+/// Reads a `jsonParsed` token account, skipping malformed entries rather than listing a
+/// zeroed mint or amount.
+fn token_account_info(parsed: &serde_json::Value) -> Option<TokenAccountInfo> {
+    let info = parsed.get("info")?;
+    let token_amount = info.get("tokenAmount")?;
+    Some(TokenAccountInfo {
+        mint: Pubkey::from_str(info.get("mint")?.as_str()?).ok()?,
+        amount: token_amount.get("amount")?.as_str()?.parse().ok()?,
+        decimals: token_amount
+            .get("decimals")
+            .and_then(|d| d.as_u64())
+            .unwrap_or(0) as u8,
+        token_name: None,
+        ui_amount: token_amount
+            .get("uiAmount")
+            .and_then(|u| u.as_f64())
+            .unwrap_or(0.0),
+    })
+}
+
+/// Top-level instructions of a `jsonParsed` message; raw messages have none parsed.
+fn outer_instructions(txn: &EncodedConfirmedTransactionWithStatusMeta) -> &[UiInstruction] {
+    match &txn.transaction.transaction {
+        solana_transaction_status::EncodedTransaction::Json(ui_txn) => match &ui_txn.message {
+            solana_transaction_status::UiMessage::Parsed(msg) => &msg.instructions,
+            solana_transaction_status::UiMessage::Raw(_) => &[],
+        },
+        _ => &[],
+    }
+}
+
+fn transaction_signatures(txn: &EncodedConfirmedTransactionWithStatusMeta) -> Vec<Signature> {
+    match &txn.transaction.transaction {
+        solana_transaction_status::EncodedTransaction::Json(parsed_txn) => parsed_txn
+            .signatures
+            .iter()
+            .filter_map(|sig| Signature::from_str(sig).ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn loaded_addresses(loaded: &OptionSerializer<UiLoadedAddresses>) -> LoadedAddresses {
+    let OptionSerializer::Some(loaded) = loaded else {
+        return LoadedAddresses::default();
+    };
+    let parse = |keys: &[String]| -> Vec<Pubkey> {
+        keys.iter()
+            .filter_map(|key| Pubkey::from_str(key).ok())
+            .collect()
+    };
+    LoadedAddresses {
+        writable: parse(&loaded.writable),
+        readonly: parse(&loaded.readonly),
+    }
+}
+
+/// Appends lookup table accounts the message didn't list (raw messages only carry the
+/// static keys), writable before readonly to match the runtime's account ordering.
+fn append_loaded_accounts(
+    accounts: &mut Vec<AccountMeta>,
+    loaded: &LoadedAddresses,
+    meta: &UiTransactionStatusMeta,
+) {
+    let writable = loaded.writable.iter().map(|pubkey| (pubkey, true));
+    let readonly = loaded.readonly.iter().map(|pubkey| (pubkey, false));
+    for (pubkey, is_writable) in writable.chain(readonly) {
+        if accounts.iter().any(|account| account.pubkey == *pubkey) {
+            continue;
+        }
+        let idx = accounts.len();
+        accounts.push(AccountMeta {
+            pubkey: *pubkey,
+            is_signer: false,
+            is_writable,
+            pre_balance: meta.pre_balances.get(idx).copied(),
+            post_balance: meta.post_balances.get(idx).copied(),
+            account_type: None,
+            source: AccountSource::Lookup,
+        });
+    }
+}
+
+/// Describes a mismatch between the account list and the pre/post balance arrays, which
+/// are matched up by index.
+fn balance_length_warning(accounts: usize, pre: usize, post: usize) -> Option<String> {
+    if pre == accounts && post == accounts {
+        return None;
+    }
+    Some(format!(
+        "RPC returned {} pre / {} post balances for {} accounts; balance changes may be inaccurate",
+        pre, post, accounts
+    ))
+}
+
+/// Number of recent epochs of vote credits to keep.
+const VOTE_CREDIT_EPOCHS: usize = 5;
+
+/// Reads the `jsonParsed` form of a vote account.
+fn parse_vote_state(parsed: &serde_json::Value) -> Option<VoteAccountInfo> {
+    let info = parsed.get("info")?;
+    let pubkey = |value: &serde_json::Value| Pubkey::from_str(value.as_str()?).ok();
+
+    // The voter authorized for the latest epoch comes last
+    let authorized_voter = info
+        .get("authorizedVoters")?
+        .as_array()?
+        .last()?
+        .get("authorizedVoter")
+        .and_then(pubkey)?;
+
+    let epoch_credits = info
+        .get("epochCredits")
+        .and_then(|credits| credits.as_array())
+        .map(|credits| {
+            credits
+                .iter()
+                .rev()
+                .take(VOTE_CREDIT_EPOCHS)
+                .filter_map(|entry| {
+                    let total_credits = json_u64(entry.get("credits")?)?;
+                    let previous = json_u64(entry.get("previousCredits")?)?;
+                    Some(EpochCredits {
+                        epoch: json_u64(entry.get("epoch")?)?,
+                        credits: total_credits.saturating_sub(previous),
+                        total_credits,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(VoteAccountInfo {
+        node_pubkey: info.get("nodePubkey").and_then(pubkey)?,
+        authorized_voter,
+        authorized_withdrawer: info.get("authorizedWithdrawer").and_then(pubkey)?,
+        commission: json_u64(info.get("commission")?)? as u8,
+        last_voted_slot: info
+            .get("votes")
+            .and_then(|votes| votes.as_array()?.last()?.get("slot"))
+            .and_then(json_u64),
+        root_slot: info.get("rootSlot").and_then(json_u64),
+        epoch_credits,
+    })
+}
+
+/// Reads the `jsonParsed` form of a stake account. Uninitialized accounts and the rewards
+/// pool have no authorities and yield `None`.
+fn parse_stake_state(
+    parsed: &serde_json::Value,
+    current_epoch: Option<u64>,
+) -> Option<StakeAccountInfo> {
+    let info = parsed.get("info")?;
+    let pubkey = |value: &serde_json::Value| Pubkey::from_str(value.as_str()?).ok();
+    let meta = info.get("meta")?;
+    let authorized = meta.get("authorized")?;
+
+    let delegation = info
+        .get("stake")
+        .and_then(|stake| stake.get("delegation"))
+        .and_then(|delegation| {
+            let activation_epoch = json_u64(delegation.get("activationEpoch")?)?;
+            // Active stake carries u64::MAX as its deactivation epoch
+            let deactivation_epoch = delegation
+                .get("deactivationEpoch")
+                .and_then(json_u64)
+                .filter(|&epoch| epoch != u64::MAX);
+            Some(StakeDelegation {
+                voter: delegation.get("voter").and_then(pubkey)?,
+                stake: json_u64(delegation.get("stake")?)?,
+                activation_epoch,
+                deactivation_epoch,
+                status: current_epoch.map(|epoch| {
+                    StakeStatus::at_epoch(activation_epoch, deactivation_epoch, epoch)
+                }),
+            })
+        });
+
+    Some(StakeAccountInfo {
+        state: parsed.get("type")?.as_str()?.to_string(),
+        staker: authorized.get("staker").and_then(pubkey)?,
+        withdrawer: authorized.get("withdrawer").and_then(pubkey)?,
+        rent_exempt_reserve: json_u64(meta.get("rentExemptReserve")?)?,
+        delegation,
+    })
+}
+
+/// Reads the `jsonParsed` form of a mint, including the names of any Token-2022
+/// extensions and the symbol from an embedded `tokenMetadata` extension.
+fn parse_mint_state(parsed: &serde_json::Value) -> Option<MintAccountInfo> {
+    if parsed.get("type")?.as_str()? != "mint" {
+        return None;
+    }
+    let info = parsed.get("info")?;
+    let pubkey = |value: &serde_json::Value| Pubkey::from_str(value.as_str()?).ok();
+    let extensions = info
+        .get("extensions")
+        .and_then(|extensions| extensions.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or(&[]);
+
+    let token_name = extensions
+        .iter()
+        .find(|ext| ext.get("extension").and_then(|name| name.as_str()) == Some("tokenMetadata"))
+        .and_then(|ext| {
+            let state = ext.get("state")?;
+            let non_empty = |key: &str| {
+                Some(state.get(key)?.as_str()?.trim())
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+            non_empty("symbol").or_else(|| non_empty("name"))
+        });
+
+    Some(MintAccountInfo {
+        supply: json_u64(info.get("supply")?)?,
+        decimals: json_u64(info.get("decimals")?)? as u8,
+        mint_authority: info.get("mintAuthority").and_then(pubkey),
+        freeze_authority: info.get("freezeAuthority").and_then(pubkey),
+        token_name,
+        extensions: extensions
+            .iter()
+            .filter_map(|ext| Some(ext.get("extension")?.as_str()?.to_string()))
+            .collect(),
+    })
+}
+
+/// Numbers in `jsonParsed` payloads are sometimes encoded as strings to avoid precision loss.
+fn json_u64(value: &serde_json::Value) -> Option<u64> {
+    value
+        .as_u64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+/// Reads a compiled System transfer: a `u32` tag of 2 (Transfer) or 11 (TransferWithSeed)
+/// followed by the `u64` lamports. The funding account comes first; TransferWithSeed lists
+/// its base account before the recipient.
+fn raw_sol_transfer(instruction: &InstructionInfo, raw: &[u8]) -> Option<SolTransfer> {
+    let tag = u32::from_le_bytes(raw.get(..4)?.try_into().ok()?);
+    let recipient = match tag {
+        2 => 1,
+        11 => 2,
+        _ => return None,
+    };
+    let lamports = raw.get(4..12)?;
+    Some(SolTransfer {
+        from: instruction.accounts.first()?.pubkey,
+        to: instruction.accounts.get(recipient)?.pubkey,
+        amount: u64::from_le_bytes(lamports.try_into().ok()?),
+    })
+}
+
+/// Reads a parsed System transfer's `info`: `{"source", "destination", "lamports"}`, which
+/// `transferWithSeed` shares.
+fn parsed_sol_transfer(info: &str) -> Option<SolTransfer> {
+    let info: serde_json::Value = serde_json::from_str(info).ok()?;
+    let pubkey = |key: &str| Pubkey::from_str(info.get(key)?.as_str()?).ok();
+    Some(SolTransfer {
+        from: pubkey("source")?,
+        to: pubkey("destination")?,
+        amount: info.get("lamports")?.as_u64()?,
+    })
+}
+
+/// Builds a transfer from a parsed Token or Token-2022 instruction. Mints are shown as a
+/// transfer out of the mint, burns as a transfer back into it.
+fn parsed_token_transfer(
+    program_id: &str,
+    parsed: &serde_json::Value,
+    token_accounts: &HashMap<Pubkey, (Pubkey, u8)>,
+) -> Option<TokenTransfer> {
+    let program_id = Pubkey::from_str(program_id).ok()?;
+    let program = match builtin_program_name(&program_id) {
+        Some(name @ ("Token Program" | "Token-2022 Program")) => name,
+        _ => return None,
+    };
+
+    let info = parsed.get("info")?;
+    let pubkey = |key: &str| {
+        info.get(key)
+            .and_then(|v| v.as_str())
+            .and_then(|v| Pubkey::from_str(v).ok())
+    };
+    // Unchecked variants carry a plain `amount`, checked ones a `tokenAmount` with decimals
+    let token_amount = info.get("tokenAmount");
+    let amount = token_amount
+        .and_then(|t| t.get("amount"))
+        .or_else(|| info.get("amount"))
+        .and_then(|v| v.as_str())?
+        .parse::<u64>()
+        .ok()?;
+    let decimals = token_amount
+        .and_then(|t| t.get("decimals"))
+        .and_then(|v| v.as_u64())
+        .map(|d| d as u8);
+
+    let (from, to, account) = match parsed.get("type")?.as_str()? {
+        "transfer" | "transferChecked" | "transferCheckedWithFee" => {
+            let from = pubkey("source")?;
+            (from, pubkey("destination")?, from)
+        }
+        "mintTo" | "mintToChecked" => {
+            let to = pubkey("account")?;
+            (pubkey("mint")?, to, to)
+        }
+        "burn" | "burnChecked" => {
+            let from = pubkey("account")?;
+            (from, pubkey("mint")?, from)
+        }
+        _ => return None,
+    };
+
+    let known = token_accounts.get(&account).copied();
+    let mint = pubkey("mint").or_else(|| known.map(|(mint, _)| mint))?;
+    let fee = info
+        .get("feeAmount")
+        .and_then(|fee| fee.get("amount"))
+        .and_then(|v| v.as_str())
+        .and_then(|v| v.parse().ok());
+
+    Some(TokenTransfer {
+        from,
+        to,
+        mint,
+        amount,
+        decimals: decimals.or_else(|| known.map(|(_, d)| d)).unwrap_or(0),
+        token_name: None,
+        program: program.to_string(),
+        fee,
+        source: TransferSource::Instruction,
+    })
+}
+
+fn is_token_program(program_id: &Pubkey) -> bool {
+    matches!(
+        builtin_program_name(program_id),
+        Some("Token Program" | "Token-2022 Program")
+    )
+}
+
+fn instruction_program(instruction: &UiInstruction, account_keys: &[Pubkey]) -> Option<Pubkey> {
+    match instruction {
+        UiInstruction::Compiled(compiled) => account_keys
+            .get(compiled.program_id_index as usize)
+            .copied(),
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => {
+            Pubkey::from_str(&parsed.program_id).ok()
+        }
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) => {
+            Pubkey::from_str(&partial.program_id).ok()
+        }
+    }
+}
+
+/// The instruction each Token or Token-2022 invocation logged (`Program log: Instruction:
+/// Transfer`), in execution order. Empty unless the logs show one invocation per Token
+/// instruction, or fewer only because they were truncated, so the two line up.
+fn token_instruction_logs(logs: &[String], token_instructions: usize) -> Vec<Option<&str>> {
+    let mut logged: Vec<Option<&str>> = Vec::new();
+    let mut awaiting_name = false;
+    let mut truncated = false;
+    for log in logs {
+        let invoked = log
+            .strip_prefix("Program ")
+            .and_then(|rest| rest.split_once(" invoke ["))
+            .map(|(program, _)| program);
+        if let Some(program) = invoked {
+            awaiting_name = Pubkey::from_str(program).is_ok_and(|p| is_token_program(&p));
+            if awaiting_name {
+                logged.push(None);
+            }
+        } else if let Some(name) = log.strip_prefix("Program log: Instruction: ") {
+            if awaiting_name {
+                if let Some(last) = logged.last_mut() {
+                    *last = Some(name);
+                }
+                awaiting_name = false;
+            }
+        } else if log == "Log truncated" {
+            truncated = true;
+        }
+    }
+
+    let aligned =
+        logged.len() == token_instructions || (truncated && logged.len() < token_instructions);
+    if aligned {
+        logged
+    } else {
+        Vec::new()
+    }
+}
+
+/// Reads a Token instruction the RPC left undecoded from its data, trusting it only when
+/// its tag agrees with the instruction the program logged running.
+fn log_token_transfer(
+    instruction: &UiInstruction,
+    logged: &str,
+    account_keys: &[Pubkey],
+    token_accounts: &HashMap<Pubkey, (Pubkey, u8)>,
+) -> Option<TokenTransfer> {
+    let program = builtin_program_name(&instruction_program(instruction, account_keys)?)?;
+    let (accounts, data) = match instruction {
+        UiInstruction::Compiled(compiled) => (
+            compiled
+                .accounts
+                .iter()
+                .map(|&idx| account_keys.get(idx as usize).copied())
+                .collect::<Option<Vec<_>>>()?,
+            &compiled.data,
+        ),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) => (
+            partial
+                .accounts
+                .iter()
+                .map(|account| Pubkey::from_str(account).ok())
+                .collect::<Option<Vec<_>>>()?,
+            &partial.data,
+        ),
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(_)) => return None,
+    };
+    let data = bs58::decode(data).into_vec().ok()?;
+    let (name, transfer) = raw_token_transfer(program, &accounts, &data, token_accounts)?;
+    (name == logged).then_some(transfer)
+}
+
+/// Reads a raw Token transfer, mint or burn: a one-byte tag, the `u64` amount and, for the
+/// checked variants, the decimals. Returns it with the name the program logs for it. Mints
+/// are a transfer out of the mint and burns one back into it, as for parsed instructions.
+fn raw_token_transfer(
+    program: &str,
+    accounts: &[Pubkey],
+    data: &[u8],
+    token_accounts: &HashMap<Pubkey, (Pubkey, u8)>,
+) -> Option<(&'static str, TokenTransfer)> {
+    let (first, second) = (accounts.first().copied()?, accounts.get(1).copied()?);
+    // TransferChecked lists the mint between its source and destination
+    let third = accounts.get(2).copied();
+    let checked = data.get(9).copied();
+    let (name, from, to, mint, decimals) = match data.first()? {
+        3 => ("Transfer", first, second, None, None),
+        7 => ("MintTo", first, second, Some(first), None),
+        8 => ("Burn", first, second, Some(second), None),
+        12 => ("TransferChecked", first, third?, Some(second), checked),
+        14 => ("MintToChecked", first, second, Some(first), checked),
+        15 => ("BurnChecked", first, second, Some(second), checked),
+        _ => return None,
+    };
+    // The token account whose balance entry knows the mint: the one that isn't the mint
+    let token_account = if Some(from) == mint { to } else { from };
+
+    let known = token_accounts.get(&token_account).copied();
+    let transfer = TokenTransfer {
+        from,
+        to,
+        mint: mint.or_else(|| known.map(|(mint, _)| mint))?,
+        amount: u64::from_le_bytes(data.get(1..9)?.try_into().ok()?),
+        // Without decimals the amount can't be scaled, so skip it rather than show it raw
+        decimals: decimals.or_else(|| known.map(|(_, d)| d))?,
+        token_name: None,
+        program: program.to_string(),
+        fee: None,
+        source: TransferSource::Logs,
+    };
+    Some((name, transfer))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TransferFeeConfig {
+    basis_points: u16,
+    maximum_fee: u64,
+}
+
+impl TransferFeeConfig {
+    /// Fee withheld from a transfer of `amount`, rounded up as the Token-2022 program does.
+    fn fee_for_amount(&self, amount: u64) -> u64 {
+        let fee = (amount as u128 * self.basis_points as u128).div_ceil(10_000);
+        fee.min(self.maximum_fee as u128) as u64
+    }
+}
+
+/// Reads a mint's `transferFeeConfig` extension. A scheduled change applies from
+/// `newerTransferFee.epoch` on; `olderTransferFee` is in force before it.
+fn transfer_fee_config(parsed: &serde_json::Value, epoch: u64) -> Option<TransferFeeConfig> {
+    let state = parsed
+        .get("info")?
+        .get("extensions")?
+        .as_array()?
+        .iter()
+        .find(|ext| ext.get("extension").and_then(|e| e.as_str()) == Some("transferFeeConfig"))?
+        .get("state")?;
+    let newer = state.get("newerTransferFee")?;
+    let fee = if epoch >= json_u64(newer.get("epoch")?)? {
+        newer
+    } else {
+        state.get("olderTransferFee")?
+    };
+
+    Some(TransferFeeConfig {
+        basis_points: json_u64(fee.get("transferFeeBasisPoints")?)? as u16,
+        maximum_fee: json_u64(fee.get("maximumFee")?)?,
+    })
+}
+
+struct TokenBalanceChange {
+    mint: String,
+    decimals: u8,
+    program: String,
+    pre: u64,
+    post: u64,
+}
+
+impl TokenBalanceChange {
+    fn from_balance(balance: &UiTransactionTokenBalance) -> Self {
+        let program = match &balance.program_id {
+            OptionSerializer::Some(id) => Pubkey::from_str(id)
+                .ok()
+                .and_then(|id| builtin_program_name(&id))
+                .unwrap_or("Token Program"),
+            _ => "Token Program",
+        };
+
+        Self {
+            mint: balance.mint.clone(),
+            decimals: balance.ui_token_amount.decimals,
+            program: program.to_string(),
+            pre: 0,
+            post: 0,
+        }
+    }
+}
+
+fn token_balances(
+    balances: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+) -> &[UiTransactionTokenBalance] {
+    match balances {
+        OptionSerializer::Some(balances) => balances.as_slice(),
+        _ => &[],
+    }
+}
+
+/// Describes what kind of account this is from its owner, executable flag and data length.
+fn classify_account(exists: bool, account: &solana_sdk::account::Account) -> String {
+    if !exists {
+        return "Account does not exist (0 lamports)".to_string();
+    }
+
+    let data = &account.data;
+    let kind = match builtin_program_name(&account.owner) {
+        _ if account.executable => "Program (Executable)",
+        Some("System Program") => match data.len() {
+            0 => "Wallet (System Account)",
+            NONCE_ACCOUNT_LEN => "Nonce Account",
+            _ => "System-owned Data Account",
+        },
+        Some("Token Program" | "Token-2022 Program") => match data.len() {
+            TOKEN_ACCOUNT_LEN => "Token Account",
+            MINT_LEN => "Token Mint",
+            MULTISIG_LEN => "Token Multisig",
+            // Token-2022 accounts with extensions store their type right after the base
+            // token account layout, with mints padded up to it
+            _ => match data.get(TOKEN_ACCOUNT_LEN) {
+                Some(1) => "Token Mint (with extensions)",
+                Some(2) => "Token Account (with extensions)",
+                _ => "Token Program Data Account",
+            },
+        },
+        Some("BPF Loader Upgradeable") => match data.first() {
+            Some(1) => "Program Buffer",
+            Some(3) => "Program Data",
+            _ => "Upgradeable Loader Account",
+        },
+        Some("Stake Program") => "Stake Account",
+        Some("Vote Program") => "Vote Account",
+        Some("Address Lookup Table") => "Address Lookup Table",
+        Some("Config Program") => "Config Account",
+        _ => {
+            return match get_program_name(&account.owner) {
+                Some(owner) => format!("Data Account (owned by {})", owner),
+                None => "Data Account".to_string(),
+            }
+        }
+    };
+    kind.to_string()
+}
+
+/// Data length of a System-owned durable nonce account.
+const NONCE_ACCOUNT_LEN: usize = 80;
+/// Data lengths of the SPL Token account layouts, without Token-2022 extensions.
+const TOKEN_ACCOUNT_LEN: usize = 165;
+const MINT_LEN: usize = 82;
+const MULTISIG_LEN: usize = 355;
+
+/// Attempts made for an RPC request that keeps failing transiently.
+const MAX_RPC_ATTEMPTS: u32 = 3;
+/// Wait before the first retry, doubled for each one after.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Runs an RPC request, retrying rate limits, timeouts and server errors with exponential
+/// backoff. Other errors, like a missing transaction, are returned straight away.
+fn with_retry<T>(mut request: impl FnMut() -> Result<T, ClientError>) -> Result<T> {
+    let mut retries = 0;
+    loop {
+        match request() {
+            Ok(value) => return Ok(value),
+            Err(e) if retries + 1 < MAX_RPC_ATTEMPTS && is_transient(&e) => {
+                std::thread::sleep(RETRY_BASE_DELAY * 2u32.pow(retries));
+                retries += 1;
+            }
+            Err(e) if retries > 0 => {
+                return Err(anyhow::anyhow!("{} (gave up after {} retries)", e, retries))
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+}
+
+/// Whether a failed request is worth repeating: rate limiting, server trouble or a
+/// dropped connection, as opposed to an answer the RPC will give again.
+fn is_transient(error: &ClientError) -> bool {
+    match error.kind() {
+        ClientErrorKind::Io(_) => true,
+        ClientErrorKind::Reqwest(e) => {
+            e.is_timeout()
+                || e.is_connect()
+                || e.status()
+                    .is_some_and(|status| status.as_u16() == 429 || status.is_server_error())
+        }
+        _ => error.to_string().contains("429 Too Many Requests"),
+    }
+}
+
+/// Largest batch `getMultipleAccounts` accepts.
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Signatures fetched per page of an account's transaction history.
+const HISTORY_PAGE_SIZE: usize = 10;
+
+/// Symbols of widely used mints, so the common cases need no metadata lookup.
+const WELL_KNOWN_TOKENS: &[(&str, &str)] = &[
+    (WRAPPED_SOL_MINT, "wSOL"),
+    ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC"),
+    ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "USDT"),
+    ("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So", "mSOL"),
+    ("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn", "JitoSOL"),
+    ("bSo13r4TkiE4KumL71LsHTPpL2euBYLFx6h9HP3piy1", "bSOL"),
+    ("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", "BONK"),
+    ("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", "JUP"),
+];
+
+fn well_known_token(mint: &Pubkey) -> Option<&'static str> {
+    let mint = mint.to_string();
+    WELL_KNOWN_TOKENS
+        .iter()
+        .find(|(address, _)| *address == mint)
+        .map(|(_, symbol)| *symbol)
+}
+
+/// Memo text from a signature status. The RPC prefixes each memo with its byte length in
+/// brackets (`[5] hello`) and joins several with `; `.
+fn memo_text(memo: &str) -> String {
+    memo.split("; ")
+        .map(|part| {
+            match part
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once("] "))
+            {
+                Some((len, text)) if len.chars().all(|c| c.is_ascii_digit()) => text,
+                _ => part,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Where the page after `page` starts, or `None` when a short page shows the history ran out.
+pub fn next_history_cursor(page: &[TransactionSummary], page_size: usize) -> Option<Signature> {
+    if page.len() < page_size {
+        return None;
+    }
+    page.last().map(|txn| txn.signature)
+}
+
+/// Reads the symbol, or failing that the name, from a Metaplex metadata account. Both are
+/// Borsh strings after the key byte, update authority and mint, and padded with NULs.
+fn metadata_token_name(data: &[u8]) -> Option<String> {
+    fn borsh_string(data: &[u8], offset: usize) -> Option<(String, usize)> {
+        let len_bytes = data.get(offset..offset + 4)?;
+        let len = u32::from_le_bytes(len_bytes.try_into().ok()?) as usize;
+        let bytes = data.get(offset + 4..offset + 4 + len)?;
+        let value = String::from_utf8_lossy(bytes)
+            .trim_matches(char::from(0))
+            .trim()
+            .to_string();
+        Some((value, offset + 4 + len))
+    }
+
+    let (name, next) = borsh_string(data, 1 + 32 + 32)?;
+    let symbol = borsh_string(data, next).map(|(symbol, _)| symbol);
+    symbol
+        .filter(|symbol| !symbol.is_empty())
+        .or(Some(name))
+        .filter(|name| !name.is_empty())
+}
+
+/// Names a Stake program instruction from its bincode `u32` tag, including the lamport
+/// amount for the instructions that carry one.
+fn stake_instruction_type(data: &[u8]) -> String {
+    let Some(tag) = data.get(..4) else {
+        return "Unknown".to_string();
+    };
+    let lamports = || {
+        data.get(4..12)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    };
+    let with_lamports = |name: &str| match lamports() {
+        Some(amount) => format!("{} ({:.9} SOL)", name, amount as f64 / 1_000_000_000.0),
+        None => name.to_string(),
+    };
+    // Authorize carries the new authority pubkey, then which authority it replaces
+    let authority = || match data.get(36..40).map(|kind| kind[0]) {
+        Some(0) => "Authorize (Staker)".to_string(),
+        Some(1) => "Authorize (Withdrawer)".to_string(),
+        _ => "Authorize".to_string(),
+    };
+
+    match u32::from_le_bytes(tag.try_into().unwrap()) {
+        0 => "Initialize".to_string(),
+        1 => authority(),
+        2 => "DelegateStake".to_string(),
+        3 => with_lamports("Split"),
+        4 => with_lamports("Withdraw"),
+        5 => "Deactivate".to_string(),
+        6 => "SetLockup".to_string(),
+        7 => "Merge".to_string(),
+        8 => "AuthorizeWithSeed".to_string(),
+        9 => "InitializeChecked".to_string(),
+        10 => "AuthorizeChecked".to_string(),
+        11 => "AuthorizeCheckedWithSeed".to_string(),
+        12 => "SetLockupChecked".to_string(),
+        13 => "GetMinimumDelegation".to_string(),
+        14 => "DeactivateDelinquent".to_string(),
+        15 => "Redelegate".to_string(),
+        16 => with_lamports("MoveStake"),
+        17 => with_lamports("MoveLamports"),
+        _ => "Unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    fn client() -> SolanaClient {
+        SolanaClient::new(Network::Devnet, Commitment::Confirmed)
+    }
+
+    fn token_instruction(kind: &str, info: serde_json::Value) -> UiInstruction {
+        serde_json::from_value(json!({
+            "program": "spl-token",
+            "programId": TOKEN_PROGRAM,
+            "parsed": { "type": kind, "info": info },
+        }))
+        .unwrap()
+    }
+
+    fn token_balance(index: u8, mint: &Pubkey, amount: u64) -> serde_json::Value {
+        json!({
+            "accountIndex": index,
+            "mint": mint.to_string(),
+            "uiTokenAmount": {
+                "uiAmount": null,
+                "decimals": 6,
+                "amount": amount.to_string(),
+                "uiAmountString": amount.to_string(),
+            },
+            "programId": TOKEN_PROGRAM,
+        })
+    }
+
+    fn meta(
+        pre_tokens: serde_json::Value,
+        post_tokens: serde_json::Value,
+    ) -> UiTransactionStatusMeta {
+        serde_json::from_value(json!({
+            "err": null,
+            "status": { "Ok": null },
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "innerInstructions": [],
+            "logMessages": [],
+            "preTokenBalances": pre_tokens,
+            "postTokenBalances": post_tokens,
+            "rewards": [],
+        }))
+        .unwrap()
+    }
+
+    /// Account 1 sends 40 of mint 9 to account 2.
+    fn transfer_meta() -> UiTransactionStatusMeta {
+        meta(
+            json!([token_balance(0, &key(9), 100), token_balance(1, &key(9), 0)]),
+            json!([token_balance(0, &key(9), 60), token_balance(1, &key(9), 40)]),
+        )
+    }
+
+    #[test]
+    fn token_transfer_takes_mint_and_decimals_from_balances() {
+        let instruction = token_instruction(
+            "transfer",
+            json!({
+                "source": key(1).to_string(),
+                "destination": key(2).to_string(),
+                "authority": key(3).to_string(),
+                "amount": "40",
+            }),
+        );
+        let transfers = client().parse_token_transfers_from_instructions(
+            &[instruction],
+            &transfer_meta(),
+            &[key(1), key(2)],
+        );
+
+        assert_eq!(transfers.len(), 1);
+        let transfer = &transfers[0];
+        assert_eq!((transfer.from, transfer.to), (key(1), key(2)));
+        assert_eq!(transfer.mint, key(9));
+        assert_eq!(transfer.amount, 40);
+        assert_eq!(transfer.decimals, 6);
+        assert_eq!(transfer.program, "Token Program");
+        assert_eq!(transfer.source, TransferSource::Instruction);
+    }
+
+    #[test]
+    fn token_transfer_checked_reads_its_own_mint_and_decimals() {
+        let instruction = token_instruction(
+            "transferChecked",
+            json!({
+                "source": key(1).to_string(),
+                "mint": key(8).to_string(),
+                "destination": key(2).to_string(),
+                "authority": key(3).to_string(),
+                "tokenAmount": {
+                    "amount": "2500",
+                    "decimals": 2,
+                    "uiAmount": 25.0,
+                    "uiAmountString": "25",
+                },
+            }),
+        );
+        let transfers = client().parse_token_transfers_from_instructions(
+            &[instruction],
+            &meta(json!([]), json!([])),
+            &[],
+        );
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].mint, key(8));
+        assert_eq!(transfers[0].amount, 2500);
+        assert_eq!(transfers[0].decimals, 2);
+    }
+
+    #[test]
+    fn mint_to_is_a_transfer_out_of_the_mint() {
+        let instruction = token_instruction(
+            "mintTo",
+            json!({
+                "mint": key(9).to_string(),
+                "account": key(2).to_string(),
+                "mintAuthority": key(3).to_string(),
+                "amount": "42",
+            }),
+        );
+        let transfers = client().parse_token_transfers_from_instructions(
+            &[instruction],
+            &transfer_meta(),
+            &[key(1), key(2)],
+        );
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(9), key(2)));
+        assert_eq!(transfers[0].amount, 42);
+        assert_eq!(transfers[0].decimals, 6);
+    }
+
+    #[test]
+    fn burn_is_a_transfer_back_into_the_mint() {
+        let instruction = token_instruction(
+            "burn",
+            json!({
+                "account": key(1).to_string(),
+                "mint": key(9).to_string(),
+                "authority": key(3).to_string(),
+                "amount": "7",
+            }),
+        );
+        let transfers = client().parse_token_transfers_from_instructions(
+            &[instruction],
+            &transfer_meta(),
+            &[key(1), key(2)],
+        );
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(9)));
+        assert_eq!(transfers[0].amount, 7);
+    }
+
+    fn raw_system_instruction(data: &[u8], accounts: &[u8], keys: &[Pubkey]) -> InstructionInfo {
+        let compiled: UiCompiledInstruction = serde_json::from_value(json!({
+            "programIdIndex": keys.len() - 1,
+            "accounts": accounts,
+            "data": bs58::encode(data).into_string(),
+        }))
+        .unwrap();
+        client().parse_raw_instruction(&compiled, keys, 0).unwrap()
+    }
+
+    fn parsed_system_instruction(kind: &str, info: serde_json::Value) -> InstructionInfo {
+        let parsed: UiParsedInstruction = serde_json::from_value(json!({
+            "program": "system",
+            "programId": "11111111111111111111111111111111",
+            "parsed": { "type": kind, "info": info },
+        }))
+        .unwrap();
+        client().parse_parsed_instruction(&parsed, 0).unwrap()
+    }
+
+    fn system_data(tag: u32, lamports: u64) -> Vec<u8> {
+        let mut data = tag.to_le_bytes().to_vec();
+        data.extend(lamports.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn raw_system_transfer_reads_lamports_from_bytes() {
+        let system = Pubkey::default();
+        let instruction = raw_system_instruction(
+            &system_data(2, 1_500_000),
+            &[0, 1],
+            &[key(1), key(2), system],
+        );
+        let transfers = client().parse_sol_transfers(&[instruction], &[]);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(2)));
+        assert_eq!(transfers[0].amount, 1_500_000);
+    }
+
+    #[test]
+    fn raw_transfer_with_seed_skips_the_base_account() {
+        let system = Pubkey::default();
+        let mut data = system_data(11, 250);
+        // Seed string and owner follow the lamports
+        data.extend(4u64.to_le_bytes());
+        data.extend(b"seed");
+        data.extend(key(7).to_bytes());
+        let instruction =
+            raw_system_instruction(&data, &[0, 1, 2], &[key(1), key(5), key(2), system]);
+        let transfers = client().parse_sol_transfers(&[instruction], &[]);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(2)));
+        assert_eq!(transfers[0].amount, 250);
+    }
+
+    #[test]
+    fn truncated_raw_system_transfer_is_skipped() {
+        let system = Pubkey::default();
+        let instruction =
+            raw_system_instruction(&[2, 0, 0, 0, 1, 2], &[0, 1], &[key(1), key(2), system]);
+
+        assert!(client().parse_sol_transfers(&[instruction], &[]).is_empty());
+    }
+
+    #[test]
+    fn parsed_system_transfer_reads_lamports_from_info() {
+        let instruction = parsed_system_instruction(
+            "transfer",
+            json!({
+                "source": key(1).to_string(),
+                "destination": key(2).to_string(),
+                "lamports": 1_500_000,
+            }),
+        );
+        let transfers = client().parse_sol_transfers(&[instruction], &[]);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(2)));
+        assert_eq!(transfers[0].amount, 1_500_000);
+    }
+
+    #[test]
+    fn parsed_transfer_with_seed_reads_lamports_from_info() {
+        let instruction = parsed_system_instruction(
+            "transferWithSeed",
+            json!({
+                "source": key(1).to_string(),
+                "sourceBase": key(5).to_string(),
+                "destination": key(2).to_string(),
+                "lamports": 250,
+                "sourceSeed": "seed",
+                "sourceOwner": key(7).to_string(),
+            }),
+        );
+        let transfers = client().parse_sol_transfers(&[instruction], &[]);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(2)));
+        assert_eq!(transfers[0].amount, 250);
+    }
+
+    fn account(owner: &str, data: Vec<u8>, executable: bool) -> solana_sdk::account::Account {
+        solana_sdk::account::Account {
+            lamports: 1_000_000,
+            data,
+            owner: Pubkey::from_str(owner).unwrap(),
+            executable,
+            rent_epoch: 0,
+        }
+    }
+
+    fn classify(owner: &str, data: Vec<u8>) -> String {
+        classify_account(true, &account(owner, data, false))
+    }
+
+    const TOKEN_2022_PROGRAM: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCQbphWkTg";
+
+    #[test]
+    fn classifies_system_accounts_by_data_length() {
+        let system = "11111111111111111111111111111111";
+        assert_eq!(classify(system, vec![]), "Wallet (System Account)");
+        assert_eq!(classify(system, vec![0; 80]), "Nonce Account");
+    }
+
+    #[test]
+    fn classifies_token_accounts_by_data_length() {
+        assert_eq!(classify(TOKEN_PROGRAM, vec![0; 165]), "Token Account");
+        assert_eq!(classify(TOKEN_PROGRAM, vec![0; 82]), "Token Mint");
+        assert_eq!(classify(TOKEN_PROGRAM, vec![0; 355]), "Token Multisig");
+    }
+
+    #[test]
+    fn classifies_token_2022_accounts_with_extensions() {
+        // The account type byte follows the 165-byte base layout
+        let mut data = vec![0; 200];
+        data[165] = 2;
+        assert_eq!(
+            classify(TOKEN_2022_PROGRAM, data.clone()),
+            "Token Account (with extensions)"
+        );
+        data[165] = 1;
+        assert_eq!(
+            classify(TOKEN_2022_PROGRAM, data),
+            "Token Mint (with extensions)"
+        );
+        assert_eq!(classify(TOKEN_2022_PROGRAM, vec![0; 165]), "Token Account");
+    }
+
+    #[test]
+    fn classifies_executable_accounts_as_programs() {
+        let program = account(
+            "BPFLoaderUpgradeab1e11111111111111111111111",
+            vec![0; 36],
+            true,
+        );
+        assert_eq!(classify_account(true, &program), "Program (Executable)");
+        assert_eq!(
+            classify_account(false, &program),
+            "Account does not exist (0 lamports)"
+        );
+    }
+
+    fn fee_mint(older_bps: u64, newer_bps: u64, newer_epoch: u64) -> serde_json::Value {
+        json!({
+            "type": "mint",
+            "info": {
+                "decimals": 6,
+                "extensions": [{
+                    "extension": "transferFeeConfig",
+                    "state": {
+                        "olderTransferFee": {
+                            "epoch": 0,
+                            "maximumFee": 1_000_000,
+                            "transferFeeBasisPoints": older_bps,
+                        },
+                        "newerTransferFee": {
+                            "epoch": newer_epoch,
+                            "maximumFee": 1_000_000,
+                            "transferFeeBasisPoints": newer_bps,
+                        },
+                    },
+                }],
+            },
+        })
+    }
+
+    #[test]
+    fn transfer_fee_config_follows_the_transaction_epoch() {
+        let mint = fee_mint(50, 100, 600);
+
+        let before = transfer_fee_config(&mint, 599).unwrap();
+        assert_eq!(before.basis_points, 50);
+        let after = transfer_fee_config(&mint, 600).unwrap();
+        assert_eq!(after.basis_points, 100);
+        assert_eq!(after.maximum_fee, 1_000_000);
+    }
+
+    #[test]
+    fn mint_without_fee_extension_has_no_fee_config() {
+        let mint = json!({ "type": "mint", "info": { "decimals": 6, "extensions": [] } });
+        assert!(transfer_fee_config(&mint, 0).is_none());
+    }
+
+    #[test]
+    fn transfer_fee_rounds_up_and_is_capped() {
+        let config = TransferFeeConfig {
+            basis_points: 100,
+            maximum_fee: 5_000,
+        };
+        assert_eq!(config.fee_for_amount(0), 0);
+        assert_eq!(config.fee_for_amount(10_000), 100);
+        assert_eq!(config.fee_for_amount(10_001), 101);
+        assert_eq!(config.fee_for_amount(1_000_000), 5_000);
+    }
+
+    #[test]
+    fn invalid_history_signature_is_an_error() {
+        assert!(history_signature("not-a-signature").is_err());
+        assert!(history_signature("").is_err());
+        assert!(history_signature(&Signature::default().to_string()).is_ok());
+    }
+
+    #[test]
+    fn invalid_program_id_is_an_error() {
+        let instruction: UiParsedInstruction = serde_json::from_value(json!({
+            "program": "spl-token",
+            "programId": "not a program id",
+            "parsed": { "type": "transfer", "info": {} },
+        }))
+        .unwrap();
+        assert!(client().parse_parsed_instruction(&instruction, 0).is_err());
+
+        let partial: UiParsedInstruction = serde_json::from_value(json!({
+            "programId": "0OIl",
+            "accounts": [],
+            "data": "",
+        }))
+        .unwrap();
+        assert!(client().parse_parsed_instruction(&partial, 0).is_err());
+    }
+
+    #[test]
+    fn token_account_with_non_numeric_amount_is_skipped() {
+        let account = |amount: &str| {
+            json!({
+                "type": "account",
+                "info": {
+                    "mint": key(9).to_string(),
+                    "owner": key(1).to_string(),
+                    "tokenAmount": {
+                        "amount": amount,
+                        "decimals": 6,
+                        "uiAmount": 1.5,
+                        "uiAmountString": "1.5",
+                    },
+                },
+            })
+        };
+
+        assert!(token_account_info(&account("lots")).is_none());
+        assert!(token_account_info(&account("-1")).is_none());
+        let token = token_account_info(&account("1500000")).unwrap();
+        assert_eq!(token.mint, key(9));
+        assert_eq!(token.amount, 1_500_000);
+        assert_eq!(token.decimals, 6);
+    }
+
+    #[test]
+    fn balance_deltas_are_the_fallback_without_token_instructions() {
+        let transfers = client().parse_token_transfers(&[], &transfer_meta(), &[key(1), key(2)]);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(2)));
+        assert_eq!(transfers[0].amount, 40);
+        assert_eq!(transfers[0].source, TransferSource::BalanceChange);
+    }
+
+    #[test]
+    fn balance_deltas_are_ignored_when_instructions_describe_the_transfer() {
+        let instruction = token_instruction(
+            "transfer",
+            json!({
+                "source": key(1).to_string(),
+                "destination": key(2).to_string(),
+                "authority": key(3).to_string(),
+                "amount": "40",
+            }),
+        );
+        let transfers =
+            client().parse_token_transfers(&[instruction], &transfer_meta(), &[key(1), key(2)]);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].source, TransferSource::Instruction);
+    }
+
+    fn raw_token_instruction(data: &[u8], accounts: &[u8], keys: &[Pubkey]) -> UiInstruction {
+        serde_json::from_value(json!({
+            "programIdIndex": keys.len() - 1,
+            "accounts": accounts,
+            "data": bs58::encode(data).into_string(),
+        }))
+        .unwrap()
+    }
+
+    fn token_data(tag: u8, amount: u64, decimals: Option<u8>) -> Vec<u8> {
+        let mut data = vec![tag];
+        data.extend(amount.to_le_bytes());
+        data.extend(decimals);
+        data
+    }
+
+    /// Logs of one top-level Token program call per name, each logging that instruction.
+    fn token_logs(names: &[&str]) -> Vec<String> {
+        names
+            .iter()
+            .flat_map(|name| {
+                [
+                    format!("Program {} invoke [1]", TOKEN_PROGRAM),
+                    format!("Program log: Instruction: {}", name),
+                    format!("Program {} success", TOKEN_PROGRAM),
+                ]
+            })
+            .collect()
+    }
+
+    fn with_logs(mut meta: UiTransactionStatusMeta, names: &[&str]) -> UiTransactionStatusMeta {
+        meta.log_messages = OptionSerializer::Some(token_logs(names));
+        meta
+    }
+
+    fn token_program() -> Pubkey {
+        Pubkey::from_str(TOKEN_PROGRAM).unwrap()
+    }
+
+    #[test]
+    fn undecoded_transfer_is_read_when_the_log_confirms_it() {
+        let keys = [key(1), key(2), key(3), token_program()];
+        let instruction = raw_token_instruction(&token_data(3, 40, None), &[0, 1, 2], &keys);
+        let meta = with_logs(transfer_meta(), &["Transfer"]);
+        let transfers =
+            client().parse_token_transfers_from_instructions(&[instruction], &meta, &keys);
+
+        assert_eq!(transfers.len(), 1);
+        let transfer = &transfers[0];
+        assert_eq!((transfer.from, transfer.to), (key(1), key(2)));
+        assert_eq!(transfer.mint, key(9));
+        assert_eq!(transfer.amount, 40);
+        assert_eq!(transfer.decimals, 6);
+        assert_eq!(transfer.program, "Token Program");
+        assert_eq!(transfer.source, TransferSource::Logs);
+    }
+
+    #[test]
+    fn undecoded_checked_transfer_takes_mint_and_decimals_from_its_data() {
+        let keys = [key(1), key(9), key(2), key(3), token_program()];
+        let data = token_data(12, 40, Some(9));
+        let instruction = raw_token_instruction(&data, &[0, 1, 2, 3], &keys);
+        let meta = with_logs(meta(json!([]), json!([])), &["TransferChecked"]);
+        let transfers =
+            client().parse_token_transfers_from_instructions(&[instruction], &meta, &keys);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!((transfers[0].from, transfers[0].to), (key(1), key(2)));
+        assert_eq!(transfers[0].mint, key(9));
+        assert_eq!(transfers[0].decimals, 9);
+    }
+
+    #[test]
+    fn undecoded_instruction_needs_a_matching_log() {
+        let keys = [key(1), key(2), key(3), token_program()];
+        let instruction = raw_token_instruction(&token_data(3, 40, None), &[0, 1, 2], &keys);
+        let read = |meta: &UiTransactionStatusMeta| {
+            client().parse_token_transfers_from_instructions(&[instruction.clone()], meta, &keys)
+        };
+
+        assert!(read(&transfer_meta()).is_empty());
+        assert!(read(&with_logs(transfer_meta(), &["Approve"])).is_empty());
+        // Without a confirmed instruction, the balance deltas still describe the transfer
+        let transfers = client().parse_token_transfers(&[instruction], &transfer_meta(), &keys);
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].source, TransferSource::BalanceChange);
+    }
+
+    #[test]
+    fn parsed_and_logged_transfers_keep_execution_order_without_repeats() {
+        let keys = [key(1), key(2), key(3), token_program()];
+        let parsed = token_instruction(
+            "transfer",
+            json!({
+                "source": key(1).to_string(),
+                "destination": key(2).to_string(),
+                "authority": key(3).to_string(),
+                "amount": "10",
+            }),
+        );
+        let raw = raw_token_instruction(&token_data(3, 30, None), &[1, 0, 2], &keys);
+        let meta = with_logs(transfer_meta(), &["Transfer", "Transfer"]);
+        let transfers = client().parse_token_transfers(&[parsed, raw], &meta, &keys);
+
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].amount, 10);
+        assert_eq!(transfers[0].source, TransferSource::Instruction);
+        assert_eq!((transfers[1].from, transfers[1].to), (key(2), key(1)));
+        assert_eq!(transfers[1].amount, 30);
+        assert_eq!(transfers[1].source, TransferSource::Logs);
+    }
+
+    #[test]
+    fn token_logs_must_line_up_with_the_token_instructions() {
+        let logs = token_logs(&["Transfer", "Burn"]);
+        assert_eq!(
+            token_instruction_logs(&logs, 2),
+            vec![Some("Transfer"), Some("Burn")]
+        );
+        assert!(token_instruction_logs(&logs, 1).is_empty());
+        assert!(token_instruction_logs(&logs, 3).is_empty());
+
+        // Truncated logs still line up with the instructions that ran first
+        let mut truncated = token_logs(&["Transfer"]);
+        truncated.push("Log truncated".to_string());
+        assert_eq!(
+            token_instruction_logs(&truncated, 2),
+            vec![Some("Transfer")]
+        );
 
-        priority_fee
+        // Other programs log their own instruction names, which don't count
+        let mut nested = vec![
+            format!("Program {} invoke [1]", key(5)),
+            "Program log: Instruction: Swap".to_string(),
+        ];
+        nested.extend(token_logs(&["Transfer"]));
+        assert_eq!(token_instruction_logs(&nested, 1), vec![Some("Transfer")]);
     }
 }
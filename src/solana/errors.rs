@@ -0,0 +1,179 @@
+use crate::solana::labels::builtin_program_name;
+use crate::solana::types::InstructionInfo;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::transaction::TransactionError;
+
+/// Plain-English reason a transaction failed. Instruction errors name the failing
+/// instruction and its program; custom program errors show the code in hex and decimal,
+/// with a description where the program is one whose codes are known.
+pub fn describe(
+    err: &TransactionError,
+    instructions: &[InstructionInfo],
+    logs: &[String],
+) -> String {
+    match err {
+        TransactionError::InstructionError(index, ix_err) => {
+            let ix = instructions.get(*index as usize);
+            let program = ix
+                .and_then(|ix| ix.program_name.as_deref())
+                .unwrap_or("unknown program");
+            let program_name = ix.and_then(|ix| builtin_program_name(&ix.program_id));
+            // Custom codes only mean something to the program that raised them, so Anchor's
+            // names apply only when an Anchor program reported the error
+            let anchor = logs.iter().any(|log| log.contains("AnchorError"));
+            format!(
+                "Instruction #{} ({}) failed: {}",
+                *index as usize + 1,
+                program,
+                describe_instruction_error(ix_err, program_name, anchor)
+            )
+        }
+        TransactionError::InsufficientFundsForFee => {
+            "Fee payer can't cover the transaction fee".to_string()
+        }
+        TransactionError::InsufficientFundsForRent { account_index } => format!(
+            "Account #{} would be left below the rent-exempt minimum",
+            account_index
+        ),
+        TransactionError::BlockhashNotFound => {
+            "Blockhash expired or not found; re-sign with a recent blockhash".to_string()
+        }
+        TransactionError::AlreadyProcessed => "Transaction was already processed".to_string(),
+        TransactionError::AccountNotFound => {
+            "An account the transaction needs (usually the fee payer) doesn't exist".to_string()
+        }
+        TransactionError::AccountInUse => {
+            "An account is locked by another transaction in the same block".to_string()
+        }
+        TransactionError::ProgramAccountNotFound => "Invoked program doesn't exist".to_string(),
+        TransactionError::WouldExceedMaxBlockCostLimit
+        | TransactionError::WouldExceedMaxAccountCostLimit
+        | TransactionError::WouldExceedAccountDataBlockLimit => {
+            "Block is full for this transaction's cost; retry later".to_string()
+        }
+        TransactionError::MaxLoadedAccountsDataSizeExceeded => {
+            "Loaded account data exceeded the transaction's limit".to_string()
+        }
+        TransactionError::DuplicateInstruction(index) => format!(
+            "Instruction #{} is a duplicate of an earlier one",
+            *index as usize + 1
+        ),
+        other => format!("{:?}", other),
+    }
+}
+
+fn describe_instruction_error(
+    err: &InstructionError,
+    program: Option<&str>,
+    anchor: bool,
+) -> String {
+    match err {
+        InstructionError::Custom(code) => {
+            let known = match program {
+                Some("Token Program") | Some("Token-2022 Program") => spl_token_error(*code),
+                Some("System Program") => system_error(*code),
+                _ if anchor => anchor_error(*code),
+                _ => None,
+            };
+            match known {
+                Some(reason) => format!("{} (custom error 0x{:x} / {})", reason, code, code),
+                None => format!("custom program error 0x{:x} ({})", code, code),
+            }
+        }
+        InstructionError::InsufficientFunds => "insufficient funds".to_string(),
+        InstructionError::InvalidArgument => "invalid argument".to_string(),
+        InstructionError::InvalidInstructionData => "invalid instruction data".to_string(),
+        InstructionError::InvalidAccountData => "invalid account data".to_string(),
+        InstructionError::AccountDataTooSmall => "account data too small".to_string(),
+        InstructionError::IncorrectProgramId => "account owned by a different program".to_string(),
+        InstructionError::MissingRequiredSignature => "missing required signature".to_string(),
+        InstructionError::AccountAlreadyInitialized => "account already initialized".to_string(),
+        InstructionError::UninitializedAccount => "account not initialized".to_string(),
+        InstructionError::NotEnoughAccountKeys => "not enough accounts passed".to_string(),
+        InstructionError::AccountNotRentExempt => "account not rent exempt".to_string(),
+        InstructionError::PrivilegeEscalation => {
+            "cross-program invocation escalated a signer or writable privilege".to_string()
+        }
+        InstructionError::ComputationalBudgetExceeded => "ran out of compute units".to_string(),
+        InstructionError::ProgramFailedToComplete => "program failed to complete".to_string(),
+        InstructionError::ReadonlyLamportChange => {
+            "changed the balance of a read-only account".to_string()
+        }
+        InstructionError::ExternalAccountLamportSpend => {
+            "spent lamports from an account it doesn't own".to_string()
+        }
+        InstructionError::UnbalancedInstruction => "lamports were created or destroyed".to_string(),
+        InstructionError::InvalidSeeds => "seeds don't derive a valid address".to_string(),
+        InstructionError::CallDepth => "cross-program invocation too deep".to_string(),
+        InstructionError::MaxSeedLengthExceeded => "seed longer than 32 bytes".to_string(),
+        other => format!("{:?}", other),
+    }
+}
+
+/// SPL Token `TokenError` codes, shared by Token-2022.
+fn spl_token_error(code: u32) -> Option<&'static str> {
+    Some(match code {
+        0 => "account not rent exempt",
+        1 => "insufficient token balance",
+        2 => "invalid mint",
+        3 => "account not associated with this mint",
+        4 => "owner does not match",
+        5 => "fixed supply, no more tokens can be minted",
+        6 => "account already in use",
+        7 => "invalid number of provided signers",
+        8 => "invalid number of required signers",
+        9 => "state is uninitialized",
+        10 => "instruction does not support native tokens",
+        11 => "non-native account can only be closed if its balance is zero",
+        12 => "invalid instruction",
+        13 => "state is invalid for requested operation",
+        14 => "operation overflowed",
+        15 => "account does not support specified authority type",
+        16 => "this token mint cannot freeze accounts",
+        17 => "account is frozen",
+        18 => "mint decimals mismatch",
+        19 => "instruction does not support non-native tokens",
+        _ => return None,
+    })
+}
+
+/// System program `SystemError` codes.
+fn system_error(code: u32) -> Option<&'static str> {
+    Some(match code {
+        0 => "account already in use",
+        1 => "account doesn't have enough lamports",
+        2 => "cannot assign account to this program id",
+        3 => "cannot allocate account data of this length",
+        4 => "length of requested seed is too long",
+        5 => "provided address does not match addressed derived from seed",
+        6 => "advancing stored nonce requires a populated RecentBlockhashes sysvar",
+        7 => "stored nonce is still in recent_blockhashes",
+        8 => "specified nonce does not match stored nonce",
+        _ => return None,
+    })
+}
+
+/// Anchor framework error codes. Codes from 6000 up are defined by each program.
+fn anchor_error(code: u32) -> Option<&'static str> {
+    Some(match code {
+        100 => "Anchor: instruction discriminator not provided",
+        101 => "Anchor: instruction discriminator not found",
+        102 => "Anchor: instruction did not deserialize",
+        2000 => "Anchor: a mut constraint was violated",
+        2001 => "Anchor: a has_one constraint was violated",
+        2002 => "Anchor: a signer constraint was violated",
+        2003 => "Anchor: a raw constraint was violated",
+        2006 => "Anchor: a seeds constraint was violated",
+        2012 => "Anchor: an address constraint was violated",
+        2014 => "Anchor: a token mint constraint was violated",
+        2015 => "Anchor: a token owner constraint was violated",
+        3001 => "Anchor: account discriminator not found",
+        3002 => "Anchor: account discriminator did not match",
+        3003 => "Anchor: failed to deserialize the account",
+        3005 => "Anchor: account not enough keys",
+        3007 => "Anchor: account owned by a different program",
+        3010 => "Anchor: account is not a signer",
+        3012 => "Anchor: account not initialized",
+        _ => return None,
+    })
+}
@@ -0,0 +1,99 @@
+use anyhow::{Context, Result};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Program names shipped with the binary, in the same format as user label files.
+const BUNDLED_LABELS: &str = include_str!("known_programs.json");
+
+static BUNDLED: OnceLock<HashMap<String, String>> = OnceLock::new();
+static LABELS: OnceLock<HashMap<String, String>> = OnceLock::new();
+static LOADED_FILES: OnceLock<Vec<PathBuf>> = OnceLock::new();
+
+fn bundled() -> &'static HashMap<String, String> {
+    BUNDLED.get_or_init(|| {
+        serde_json::from_str(BUNDLED_LABELS).expect("bundled known_programs.json is valid")
+    })
+}
+
+/// Merges the bundled labels with the given JSON files (`{ "<address>": "<label>" }`).
+/// Later files override earlier ones. Only the first call has any effect.
+pub fn load(paths: &[PathBuf]) -> Result<()> {
+    let mut labels = bundled().clone();
+
+    for path in paths {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read labels file {}", path.display()))?;
+        let file: HashMap<String, String> = serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid labels file {}", path.display()))?;
+        labels.extend(file);
+    }
+
+    let _ = LABELS.set(labels);
+    let _ = LOADED_FILES.set(paths.to_vec());
+    Ok(())
+}
+
+/// User label files merged in by `load`, in precedence order.
+pub fn loaded_files() -> &'static [PathBuf] {
+    LOADED_FILES.get().map_or(&[], Vec::as_slice)
+}
+
+/// Label for any address, including user-supplied overrides.
+pub fn get_address_label(address: &Pubkey) -> Option<&'static str> {
+    LABELS
+        .get()
+        .unwrap_or_else(bundled)
+        .get(&address.to_string())
+        .map(String::as_str)
+}
+
+/// Name of a built-in program, ignoring user overrides. Instruction decoders match on
+/// these names, so they must not change when a label file renames a program.
+pub fn builtin_program_name(program_id: &Pubkey) -> Option<&'static str> {
+    bundled().get(&program_id.to_string()).map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn compute_budget_resolves_to_its_name() {
+        let program = Pubkey::from_str("ComputeBudget111111111111111111111111111111").unwrap();
+        assert_eq!(builtin_program_name(&program), Some("Compute Budget"));
+    }
+
+    #[test]
+    fn bundled_protocol_programs_resolve_to_their_names() {
+        // Address, then the name it should resolve to
+        let known = "\
+            JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4 Jupiter Aggregator v6
+            JUP4Fb2cqiRUcaTHdrPC8h2gNsA2ETXiPDD33WcGuJB Jupiter Aggregator v4
+            675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8 Raydium AMM v4
+            CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK Raydium CLMM
+            whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc Orca Whirlpools
+            srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX OpenBook DEX
+            opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb OpenBook v2
+            FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH Pyth Oracle
+            MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr Memo Program
+            Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo Memo Program (v1)
+            metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s Metaplex Token Metadata";
+        for line in known.lines() {
+            let (address, name) = line.trim().split_once(' ').unwrap();
+            let program = Pubkey::from_str(address).unwrap();
+            assert_eq!(builtin_program_name(&program), Some(name), "{}", address);
+        }
+    }
+
+    #[test]
+    fn every_bundled_key_is_a_pubkey_with_a_name() {
+        for (address, name) in bundled() {
+            let program = Pubkey::from_str(address)
+                .unwrap_or_else(|_| panic!("'{}' ({}) is not a valid pubkey", address, name));
+            assert_eq!(builtin_program_name(&program), Some(name.as_str()));
+        }
+    }
+}
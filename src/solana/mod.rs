@@ -1,14 +1,22 @@
 pub mod client;
+pub mod errors;
+pub mod labels;
+pub mod summary;
 pub mod types;
 
 pub use client::SolanaClient;
 pub use types::*;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use serde::Serialize;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Network {
     Mainnet,
     Devnet,
     Testnet,
+    /// Any other RPC endpoint, e.g. a private provider or a local test validator
+    Custom(String),
 }
 
 impl Network {
@@ -17,6 +25,7 @@ impl Network {
             Network::Mainnet => "https://api.mainnet-beta.solana.com",
             Network::Devnet => "https://api.devnet.solana.com",
             Network::Testnet => "https://api.testnet.solana.com",
+            Network::Custom(url) => url,
         }
     }
 
@@ -25,22 +34,80 @@ impl Network {
             Network::Mainnet => "Mainnet",
             Network::Devnet => "Devnet",
             Network::Testnet => "Testnet",
+            Network::Custom(_) => "Custom",
+        }
+    }
+
+    /// Parses a public cluster name as accepted on the command line.
+    pub fn from_name(name: &str) -> Option<Network> {
+        match name.to_ascii_lowercase().as_str() {
+            "mainnet" | "mainnet-beta" => Some(Network::Mainnet),
+            "devnet" => Some(Network::Devnet),
+            "testnet" => Some(Network::Testnet),
+            _ => None,
         }
     }
 
+    /// Next network in selection order. Moving onto `Custom` yields an empty URL for the
+    /// caller to fill in.
     pub fn next(&self) -> Network {
         match self {
             Network::Mainnet => Network::Devnet,
             Network::Devnet => Network::Testnet,
-            Network::Testnet => Network::Mainnet,
+            Network::Testnet => Network::Custom(String::new()),
+            Network::Custom(_) => Network::Mainnet,
         }
     }
 
     pub fn prev(&self) -> Network {
         match self {
-            Network::Mainnet => Network::Testnet,
+            Network::Mainnet => Network::Custom(String::new()),
             Network::Devnet => Network::Mainnet,
             Network::Testnet => Network::Devnet,
+            Network::Custom(_) => Network::Testnet,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Commitment {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl Commitment {
+    pub fn config(&self) -> CommitmentConfig {
+        match self {
+            Commitment::Processed => CommitmentConfig::processed(),
+            Commitment::Confirmed => CommitmentConfig::confirmed(),
+            Commitment::Finalized => CommitmentConfig::finalized(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Commitment::Processed => "processed",
+            Commitment::Confirmed => "confirmed",
+            Commitment::Finalized => "finalized",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Commitment> {
+        match name.to_ascii_lowercase().as_str() {
+            "processed" => Some(Commitment::Processed),
+            "confirmed" => Some(Commitment::Confirmed),
+            "finalized" => Some(Commitment::Finalized),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> Commitment {
+        match self {
+            Commitment::Processed => Commitment::Confirmed,
+            Commitment::Confirmed => Commitment::Finalized,
+            Commitment::Finalized => Commitment::Processed,
         }
     }
 }
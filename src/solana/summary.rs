@@ -0,0 +1,232 @@
+use crate::solana::labels::builtin_program_name;
+use crate::solana::types::{
+    get_program_name, TokenTransfer, TransactionData, TransactionStatus, TOKEN_METADATA_PROGRAM,
+};
+use crate::utils::format::{format_balance, BalanceUnit};
+use crate::utils::time::relative_time;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+
+/// Best-guess, human-readable label for what a transaction did, inferred from the
+/// programs and instruction types it contains.
+pub fn classify(data: &TransactionData) -> &'static str {
+    let mut programs = HashSet::new();
+    let mut has_metadata = false;
+    let mut types = HashSet::new();
+
+    for ix in &data.instructions {
+        match builtin_program_name(&ix.program_id) {
+            // Compute budget settings accompany nearly every transaction and say nothing
+            Some("Compute Budget") => continue,
+            Some(name) => {
+                has_metadata |= ix.program_id == TOKEN_METADATA_PROGRAM;
+                programs.insert(name);
+            }
+            None => {
+                programs.insert("Other");
+            }
+        }
+        // Parsed instructions use camelCase types, raw ones PascalCase
+        types.insert(ix.instruction_type.to_lowercase());
+    }
+
+    let has = |program: &str| programs.contains(program);
+    let has_type = |ty: &str| types.contains(ty);
+    let minted = has_type("mintto") || has_type("minttochecked");
+    let mints: HashSet<_> = data.token_transfers.iter().map(|t| t.mint).collect();
+
+    if has("BPF Loader Upgradeable") || has("BPF Loader") || has("BPF Loader (Legacy)") {
+        "Program Deploy"
+    } else if has("Vote Program") {
+        "Vote"
+    } else if has("Stake Program") {
+        if has_type("delegate") || has_type("delegatestake") {
+            "Stake Delegation"
+        } else {
+            "Stake Management"
+        }
+    } else if has_metadata && minted {
+        "NFT Mint"
+    } else if minted {
+        "Token Mint"
+    } else if mints.len() >= 2 {
+        "Token Swap"
+    } else if !mints.is_empty() {
+        "Token Transfer"
+    } else if programs.len() == 1 && has("System Program") {
+        if types.iter().all(|ty| ty.starts_with("transfer")) {
+            "SOL Transfer"
+        } else if has_type("createaccount") {
+            "Account Creation"
+        } else {
+            "System Operation"
+        }
+    } else if programs.is_empty() {
+        "Compute Budget Only"
+    } else {
+        "Program Interaction"
+    }
+}
+
+/// Headline sentence saying what a transaction did, e.g. `Swapped 1.5 SOL for 230 USDC
+/// via Jupiter Aggregator v6` or `Transferred 0.5 SOL from A to B`. Falls back to the
+/// classification and the programs involved when the transfers don't tell the story.
+pub fn describe(data: &TransactionData) -> String {
+    let sentence = describe_transfers(data).unwrap_or_else(|| {
+        let programs = main_programs(data);
+        if programs.is_empty() {
+            classify(data).to_string()
+        } else {
+            format!("{} via {}", classify(data), programs.join(", "))
+        }
+    });
+    match data.status {
+        TransactionStatus::Success => sentence,
+        TransactionStatus::Failed(_) => format!("Failed: {}", sentence),
+    }
+}
+
+fn describe_transfers(data: &TransactionData) -> Option<String> {
+    let transfers = &data.token_transfers;
+    let mints: HashSet<_> = transfers.iter().map(|t| t.mint).collect();
+
+    if mints.len() >= 2 {
+        // What left first and what arrived last, ignoring the hops in between
+        let sold = transfers.first()?;
+        let bought = transfers.iter().rev().find(|t| t.mint != sold.mint)?;
+        let via = main_programs(data)
+            .into_iter()
+            .find(|name| !is_plumbing(name))
+            .map(|name| format!(" via {}", name))
+            .unwrap_or_default();
+        return Some(format!(
+            "Swapped {} for {}{}",
+            token_amount(sold),
+            token_amount(bought),
+            via
+        ));
+    }
+
+    if let Some(first) = transfers.first() {
+        let more = if transfers.len() > 1 {
+            format!(" (+{} more)", transfers.len() - 1)
+        } else {
+            String::new()
+        };
+        let sentence = if first.from == first.mint {
+            format!("Minted {} to {}", token_amount(first), short(&first.to))
+        } else if first.to == first.mint {
+            format!("Burned {} from {}", token_amount(first), short(&first.from))
+        } else {
+            format!(
+                "Transferred {} from {} to {}",
+                token_amount(first),
+                short(&first.from),
+                short(&first.to)
+            )
+        };
+        return Some(sentence + &more);
+    }
+
+    match data.sol_transfers.as_slice() {
+        [] => None,
+        [transfer] => Some(format!(
+            "Transferred {} from {} to {}",
+            format_balance(transfer.amount, BalanceUnit::Sol),
+            short(&transfer.from),
+            short(&transfer.to)
+        )),
+        transfers => Some(format!(
+            "Transferred {} in {} transfers",
+            format_balance(transfers.iter().map(|t| t.amount).sum(), BalanceUnit::Sol),
+            transfers.len()
+        )),
+    }
+}
+
+/// Named programs the transaction called, in order, leaving out compute budget settings.
+fn main_programs(data: &TransactionData) -> Vec<String> {
+    let mut programs: Vec<String> = Vec::new();
+    for ix in &data.instructions {
+        if builtin_program_name(&ix.program_id) == Some("Compute Budget") {
+            continue;
+        }
+        let name = get_program_name(&ix.program_id)
+            .map(str::to_string)
+            .unwrap_or_else(|| short(&ix.program_id));
+        if !programs.contains(&name) {
+            programs.push(name);
+        }
+    }
+    programs
+}
+
+/// Programs that move tokens on behalf of others rather than being what a swap went
+/// through.
+fn is_plumbing(program: &str) -> bool {
+    matches!(
+        program,
+        "System Program" | "Token Program" | "Token-2022 Program" | "Associated Token Account"
+    )
+}
+
+/// Token amount with its symbol, e.g. `230 USDC`.
+fn token_amount(transfer: &TokenTransfer) -> String {
+    let amount = transfer.amount as f64 / 10f64.powi(transfer.decimals as i32);
+    let token = transfer
+        .token_name
+        .clone()
+        .unwrap_or_else(|| format!("{}…", &transfer.mint.to_string()[..4]));
+    format!("{} {}", amount, token)
+}
+
+fn short(pubkey: &Pubkey) -> String {
+    let s = pubkey.to_string();
+    format!("{}…{}", &s[..4], &s[s.len() - 4..])
+}
+
+/// Dense single-line digest of a transaction with amounts in `unit`, e.g.
+/// `✓ 2 ix | System,Token | 0.000005000 SOL fee | 1.5 USDC transferred | slot 12345 | 2m ago`.
+pub fn one_line_summary(data: &TransactionData, unit: BalanceUnit) -> String {
+    let status = match data.status {
+        TransactionStatus::Success => "✓",
+        TransactionStatus::Failed(_) => "✗",
+    };
+    format!("{} {}", status, one_line_details(data, unit))
+}
+
+/// [`one_line_summary`] without the status mark, for lists that draw their own.
+pub fn one_line_details(data: &TransactionData, unit: BalanceUnit) -> String {
+    let programs = main_programs(data);
+    let programs: Vec<&str> = programs
+        .iter()
+        .map(|name| name.trim_end_matches(" Program"))
+        .collect();
+    let mut parts = vec![
+        format!("{} ix", data.instructions.len()),
+        if programs.is_empty() {
+            "Compute Budget".to_string()
+        } else {
+            programs.join(",")
+        },
+        format!("{} fee", format_balance(data.fee, unit)),
+    ];
+
+    if let Some(transfer) = data.token_transfers.first() {
+        let mut moved = format!("{} transferred", token_amount(transfer));
+        if data.token_transfers.len() > 1 {
+            moved.push_str(&format!(" (+{} more)", data.token_transfers.len() - 1));
+        }
+        parts.push(moved);
+    } else if !data.sol_transfers.is_empty() {
+        let total: u64 = data.sol_transfers.iter().map(|t| t.amount).sum();
+        parts.push(format!("{} transferred", format_balance(total, unit)));
+    }
+
+    parts.push(format!("slot {}", data.slot));
+    if let Some(time) = data.block_time {
+        parts.push(relative_time(time));
+    }
+
+    parts.join(" | ")
+}
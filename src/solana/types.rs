@@ -1,15 +1,29 @@
 #![allow(dead_code)]
+use crate::solana::labels::builtin_program_name;
+use crate::solana::Commitment;
 use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
+use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TransactionData {
+    #[serde(serialize_with = "serialize_display")]
     pub signature: Signature,
+    /// One-sentence description of what the transaction did
+    pub summary: String,
+    /// Every signature, in signer order; `signatures[i]` belongs to `accounts[i]`
+    #[serde(serialize_with = "serialize_display_seq")]
+    pub signatures: Vec<Signature>,
     pub slot: u64,
+    /// Commitment level the transaction was fetched at
+    pub commitment: Commitment,
     pub block_time: Option<DateTime<Utc>>,
     pub fee: u64,
     pub status: TransactionStatus,
     pub instructions: Vec<InstructionInfo>,
+    /// Instructions invoked via CPI, indexed by the outer instruction that made the calls
+    pub inner_instructions: Vec<Vec<InstructionInfo>>,
     pub accounts: Vec<AccountMeta>,
     pub logs: Vec<String>,
     pub compute_units_consumed: Option<u64>,
@@ -18,69 +32,541 @@ pub struct TransactionData {
     pub sol_transfers: Vec<SolTransfer>,
     pub priority_fee: Option<u64>,
     pub max_compute_units: Option<u64>,
+    /// Requested cap, in bytes, on the data of all accounts the transaction loads
+    pub loaded_accounts_data_size_limit: Option<u32>,
+    /// Address lookup tables referenced by a v0 transaction, and whether they still resolve
+    pub lookup_tables: Vec<LookupTableInfo>,
+    /// Accounts a v0 transaction loaded through its lookup tables
+    pub loaded_addresses: LoadedAddresses,
+    /// Set when the RPC's balance arrays don't line up with the account list, in which
+    /// case per-account balance changes may be wrong or missing.
+    pub balance_warning: Option<String>,
+    /// Human-readable explanation of the failure, for failed transactions
+    pub failure_reason: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+/// Highest compute unit limit a transaction may request.
+pub const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+/// Base fee charged per signature.
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+/// Compute units each instruction is granted when the transaction sets no limit.
+pub const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+impl TransactionData {
+    /// Whether the transaction failed by running out of compute units.
+    pub fn exceeded_compute_limit(&self) -> bool {
+        let status_says_so = matches!(
+            &self.status,
+            TransactionStatus::Failed(e) if e.contains("ComputationalBudgetExceeded")
+        );
+        status_says_so
+            || self.logs.iter().any(|log| {
+                log.contains("exceeded CUs meter") || log.contains("Computational budget exceeded")
+            })
+    }
+
+    /// Whether the transaction failed by loading more account data than its limit allows.
+    pub fn exceeded_loaded_accounts_data_size(&self) -> bool {
+        matches!(
+            &self.status,
+            TransactionStatus::Failed(e) if e.contains("MaxLoadedAccountsDataSizeExceeded")
+        )
+    }
+
+    /// Compute unit limit worth retrying with after running out: the units consumed before
+    /// the failure plus 20% headroom, rounded up to the next thousand.
+    pub fn suggested_compute_limit(&self) -> Option<u64> {
+        if !self.exceeded_compute_limit() {
+            return None;
+        }
+        let consumed = self.compute_units_consumed?;
+        let padded = consumed + consumed / 5;
+        Some((padded.div_ceil(1000) * 1000).min(MAX_COMPUTE_UNIT_LIMIT))
+    }
+
+    /// Requested compute unit limit, or the one implied by the instruction count when the
+    /// transaction has no SetComputeUnitLimit.
+    pub fn compute_unit_limit(&self) -> u64 {
+        self.max_compute_units.unwrap_or_else(|| {
+            let instructions = self
+                .instructions
+                .iter()
+                .filter(|ix| builtin_program_name(&ix.program_id) != Some("Compute Budget"))
+                .count() as u64;
+            (instructions * DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT).min(MAX_COMPUTE_UNIT_LIMIT)
+        })
+    }
+
+    /// Lamports paid on top of the base fee: the compute unit price (micro-lamports) times
+    /// the requested limit, rounded up. `None` when no price was set.
+    pub fn priority_fee_lamports(&self) -> Option<u64> {
+        let price = self.priority_fee.filter(|&price| price > 0)?;
+        let micro_lamports = price as u128 * self.compute_unit_limit() as u128;
+        Some(micro_lamports.div_ceil(1_000_000) as u64)
+    }
+
+    /// Signature count times the per-signature fee, the part of the fee every
+    /// transaction pays.
+    pub fn base_fee(&self) -> u64 {
+        self.signatures.len().max(1) as u64 * LAMPORTS_PER_SIGNATURE
+    }
+
+    /// The first signer, which pays the fee.
+    pub fn fee_payer(&self) -> Option<&AccountMeta> {
+        self.accounts.first()
+    }
+
+    /// Fee payer's balance change, including the fee.
+    pub fn fee_payer_delta(&self) -> Option<i64> {
+        let payer = self.fee_payer()?;
+        Some(payer.post_balance? as i64 - payer.pre_balance? as i64)
+    }
+
+    /// Lamports credited across all accounts. Fees aren't credited to any listed account,
+    /// so this is the SOL that changed hands.
+    pub fn sol_moved(&self) -> u64 {
+        self.accounts
+            .iter()
+            .filter_map(|acc| acc.post_balance?.checked_sub(acc.pre_balance?))
+            .sum()
+    }
+
+    /// Indices into `accounts`, signers first, then writable, then read-only accounts,
+    /// keeping the transaction's order within each group.
+    pub fn grouped_account_indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..self.accounts.len()).collect();
+        indices.sort_by_key(|&i| AccountSection::of(&self.accounts[i]));
+        indices
+    }
+}
+
+/// Privilege group an account is listed under on the Accounts tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AccountSection {
+    Signers,
+    Writable,
+    ReadOnly,
+}
+
+impl AccountSection {
+    pub fn of(account: &AccountMeta) -> Self {
+        if account.is_signer {
+            Self::Signers
+        } else if account.is_writable {
+            Self::Writable
+        } else {
+            Self::ReadOnly
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::Signers => "Signers",
+            Self::Writable => "Writable",
+            Self::ReadOnly => "Read-only",
+        }
+    }
+}
+
+/// Accounts resolved from address lookup tables, which follow the statically listed keys
+/// in the account list (writable first, then readonly).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LoadedAddresses {
+    #[serde(serialize_with = "serialize_display_seq")]
+    pub writable: Vec<Pubkey>,
+    #[serde(serialize_with = "serialize_display_seq")]
+    pub readonly: Vec<Pubkey>,
+}
+
+impl LoadedAddresses {
+    pub fn len(&self) -> usize {
+        self.writable.len() + self.readonly.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, pubkey: &Pubkey) -> bool {
+        self.writable.contains(pubkey) || self.readonly.contains(pubkey)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LookupTableInfo {
+    #[serde(serialize_with = "serialize_display")]
+    pub address: Pubkey,
+    pub status: LookupTableStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum LookupTableStatus {
+    Active,
+    /// Deactivated at this slot; closed once the cooldown passes
+    Deactivated(u64),
+    /// Closed, so the transaction's looked-up accounts can no longer be resolved from it
+    NotFound,
+    /// The RPC request failed
+    Unavailable,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub enum TransactionStatus {
     Success,
     Failed(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InstructionInfo {
+    #[serde(serialize_with = "serialize_display")]
     pub program_id: Pubkey,
     pub program_name: Option<String>,
     pub instruction_type: String,
     pub data: String,
+    /// Decoded instruction bytes, when the RPC returned them undecoded by a parser
+    #[serde(skip_serializing)]
+    pub raw_data: Option<Vec<u8>>,
     pub accounts: Vec<AccountMeta>,
     pub compute_units_consumed: Option<u64>,
 }
 
-#[derive(Debug, Clone)]
+impl InstructionInfo {
+    /// Hex of the first 8 data bytes, which is the Anchor instruction discriminator.
+    pub fn discriminator(&self) -> Option<String> {
+        let raw = self.raw_data.as_ref().filter(|raw| !raw.is_empty())?;
+        Some(raw.iter().take(8).map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Text of a Memo program instruction. Memos are meant to be UTF-8; anything else is
+    /// shown as hex.
+    pub fn memo_text(&self) -> Option<String> {
+        if !matches!(
+            builtin_program_name(&self.program_id),
+            Some("Memo Program") | Some("Memo Program (v1)")
+        ) {
+            return None;
+        }
+        let Some(raw) = &self.raw_data else {
+            // Parsed by the RPC, which already decoded the text
+            return Some(self.data.clone());
+        };
+        Some(match std::str::from_utf8(raw) {
+            Ok(text) => text.to_string(),
+            Err(_) => {
+                let hex: String = raw.iter().map(|b| format!("{:02x}", b)).collect();
+                format!("0x{} (not valid UTF-8)", hex)
+            }
+        })
+    }
+
+    /// Decoded arguments of an unparsed System program instruction: a little-endian u32
+    /// tag followed by the fields in bincode layout.
+    pub fn system_instruction(&self) -> Option<SystemInstruction> {
+        if builtin_program_name(&self.program_id) != Some("System Program") {
+            return None;
+        }
+        let raw = self.raw_data.as_deref()?;
+        let u64_at = |offset: usize| {
+            Some(u64::from_le_bytes(
+                raw.get(offset..offset + 8)?.try_into().ok()?,
+            ))
+        };
+        match u32::from_le_bytes(raw.get(..4)?.try_into().ok()?) {
+            0 => Some(SystemInstruction::CreateAccount {
+                lamports: u64_at(4)?,
+                space: u64_at(12)?,
+                owner: Pubkey::try_from(raw.get(20..52)?).ok()?,
+            }),
+            2 => Some(SystemInstruction::Transfer {
+                lamports: u64_at(4)?,
+            }),
+            8 => Some(SystemInstruction::Allocate { space: u64_at(4)? }),
+            _ => None,
+        }
+    }
+
+    /// Decoded argument of a Compute Budget instruction: `[tag, little-endian value]`.
+    pub fn compute_budget(&self) -> Option<ComputeBudgetInstruction> {
+        if builtin_program_name(&self.program_id) != Some("Compute Budget") {
+            return None;
+        }
+        let raw = self.raw_data.as_deref()?;
+        let u32_arg = || Some(u32::from_le_bytes(raw.get(1..5)?.try_into().ok()?));
+        match raw.first()? {
+            1 => u32_arg().map(ComputeBudgetInstruction::RequestHeapFrame),
+            2 => u32_arg().map(ComputeBudgetInstruction::SetComputeUnitLimit),
+            3 => Some(ComputeBudgetInstruction::SetComputeUnitPrice(
+                u64::from_le_bytes(raw.get(1..9)?.try_into().ok()?),
+            )),
+            4 => u32_arg().map(ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SystemInstruction {
+    CreateAccount {
+        lamports: u64,
+        space: u64,
+        owner: Pubkey,
+    },
+    Transfer {
+        lamports: u64,
+    },
+    Allocate {
+        space: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComputeBudgetInstruction {
+    /// Heap size in bytes
+    RequestHeapFrame(u32),
+    SetComputeUnitLimit(u32),
+    /// Price in micro-lamports per compute unit
+    SetComputeUnitPrice(u64),
+    /// Limit in bytes
+    SetLoadedAccountsDataSizeLimit(u32),
+}
+
+impl Display for ComputeBudgetInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RequestHeapFrame(bytes) => write!(f, "RequestHeapFrame: {} bytes", bytes),
+            Self::SetComputeUnitLimit(units) => write!(f, "SetComputeUnitLimit: {}", units),
+            Self::SetComputeUnitPrice(price) => {
+                write!(f, "SetComputeUnitPrice: {} µLamports/CU", price)
+            }
+            Self::SetLoadedAccountsDataSizeLimit(bytes) => {
+                write!(f, "SetLoadedAccountsDataSizeLimit: {} bytes", bytes)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct AccountMeta {
+    #[serde(serialize_with = "serialize_display")]
     pub pubkey: Pubkey,
     pub is_signer: bool,
     pub is_writable: bool,
     pub pre_balance: Option<u64>,
     pub post_balance: Option<u64>,
     pub account_type: Option<String>,
+    pub source: AccountSource,
 }
 
-#[derive(Debug, Clone)]
+/// Whether an account was listed in the message itself or loaded from an address lookup
+/// table, which v0 transactions can reference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountSource {
+    Static,
+    Lookup,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenTransfer {
+    #[serde(serialize_with = "serialize_display")]
     pub from: Pubkey,
+    #[serde(serialize_with = "serialize_display")]
     pub to: Pubkey,
+    #[serde(serialize_with = "serialize_display")]
     pub mint: Pubkey,
     pub amount: u64,
     pub decimals: u8,
     pub token_name: Option<String>,
     pub program: String,
+    /// Token-2022 transfer fee withheld from `amount`; the recipient nets `amount - fee`.
+    pub fee: Option<u64>,
+    pub source: TransferSource,
+}
+
+/// Where a token transfer was reconstructed from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum TransferSource {
+    /// A parsed Token program instruction, the most precise source
+    Instruction,
+    /// An instruction the RPC couldn't parse, read from its data after the program's log
+    /// confirmed which instruction it was
+    Logs,
+    BalanceChange,
+}
+
+impl TransferSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TransferSource::Instruction => "instruction",
+            TransferSource::Logs => "logs",
+            TransferSource::BalanceChange => "balance change",
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SolTransfer {
+    #[serde(serialize_with = "serialize_display")]
     pub from: Pubkey,
+    #[serde(serialize_with = "serialize_display")]
     pub to: Pubkey,
     pub amount: u64,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AccountData {
+    #[serde(serialize_with = "serialize_display")]
     pub pubkey: Pubkey,
+    pub exists: bool,
     pub lamports: u64,
+    #[serde(serialize_with = "serialize_display")]
     pub owner: Pubkey,
     pub executable: bool,
     pub rent_epoch: u64,
     pub data_size: usize,
+    #[serde(serialize_with = "serialize_hex")]
+    pub data: Vec<u8>,
     pub token_accounts: Vec<TokenAccountInfo>,
     pub recent_transactions: Vec<TransactionSummary>,
     pub account_type: String,
     pub is_rent_exempt: bool,
     pub min_balance_for_rent_exemption: Option<u64>,
+    /// Decoded vote state, for accounts owned by the Vote program
+    pub vote_state: Option<VoteAccountInfo>,
+    /// Decoded stake state, for accounts owned by the Stake program
+    pub stake_state: Option<StakeAccountInfo>,
+    /// Decoded mint state, for accounts that are themselves token mints
+    pub mint_state: Option<MintAccountInfo>,
+    /// Oldest loaded signature, where the next page of history starts; `None` once the
+    /// whole history is loaded
+    #[serde(skip)]
+    pub history_before: Option<Signature>,
+    #[serde(skip)]
+    pub history_page_size: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VoteAccountInfo {
+    #[serde(serialize_with = "serialize_display")]
+    pub node_pubkey: Pubkey,
+    #[serde(serialize_with = "serialize_display")]
+    pub authorized_voter: Pubkey,
+    #[serde(serialize_with = "serialize_display")]
+    pub authorized_withdrawer: Pubkey,
+    pub commission: u8,
+    pub last_voted_slot: Option<u64>,
+    pub root_slot: Option<u64>,
+    /// Most recent epochs first
+    pub epoch_credits: Vec<EpochCredits>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EpochCredits {
+    pub epoch: u64,
+    /// Credits earned during this epoch
+    pub credits: u64,
+    /// Cumulative credits at the end of this epoch
+    pub total_credits: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MintAccountInfo {
+    /// Raw units; divide by `10^decimals` for whole tokens
+    pub supply: u64,
+    pub decimals: u8,
+    /// `None` once minting is permanently disabled
+    #[serde(serialize_with = "serialize_display_opt")]
+    pub mint_authority: Option<Pubkey>,
+    #[serde(serialize_with = "serialize_display_opt")]
+    pub freeze_authority: Option<Pubkey>,
+    pub token_name: Option<String>,
+    /// Token-2022 extension names as reported by the RPC, e.g. `transferFeeConfig`
+    pub extensions: Vec<String>,
+}
+
+impl MintAccountInfo {
+    /// Supply in whole tokens.
+    pub fn ui_supply(&self) -> f64 {
+        self.supply as f64 / 10f64.powi(self.decimals as i32)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StakeAccountInfo {
+    /// `initialized` or `delegated`, as reported by the RPC
+    pub state: String,
+    #[serde(serialize_with = "serialize_display")]
+    pub staker: Pubkey,
+    #[serde(serialize_with = "serialize_display")]
+    pub withdrawer: Pubkey,
+    pub rent_exempt_reserve: u64,
+    /// Only delegated stake accounts have one
+    pub delegation: Option<StakeDelegation>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StakeDelegation {
+    #[serde(serialize_with = "serialize_display")]
+    pub voter: Pubkey,
+    /// Delegated lamports
+    pub stake: u64,
+    pub activation_epoch: u64,
+    /// `None` until the stake is deactivated
+    pub deactivation_epoch: Option<u64>,
+    /// `None` if the current epoch couldn't be fetched
+    pub status: Option<StakeStatus>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StakeStatus {
+    Activating,
+    Active,
+    Deactivating,
+    Inactive,
+}
+
+impl StakeStatus {
+    /// Where a delegation stands in `current_epoch`. Assumes warmup and cooldown finish
+    /// within one epoch, which holds unless a large share of the network's stake moves at
+    /// once.
+    pub fn at_epoch(
+        activation_epoch: u64,
+        deactivation_epoch: Option<u64>,
+        current_epoch: u64,
+    ) -> Self {
+        match deactivation_epoch {
+            Some(epoch) if epoch < current_epoch => Self::Inactive,
+            Some(_) => Self::Deactivating,
+            // Stake delegated at genesis is active from the start
+            None if activation_epoch == u64::MAX => Self::Active,
+            None if activation_epoch >= current_epoch => Self::Activating,
+            None => Self::Active,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Activating => "Activating",
+            Self::Active => "Active",
+            Self::Deactivating => "Deactivating",
+            Self::Inactive => "Inactive",
+        }
+    }
+}
+
+impl StakeDelegation {
+    /// Lamports earning rewards this epoch: all of the stake while active or cooling down.
+    pub fn active_stake(&self) -> Option<u64> {
+        self.status.map(|status| match status {
+            StakeStatus::Active | StakeStatus::Deactivating => self.stake,
+            StakeStatus::Activating | StakeStatus::Inactive => 0,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenAccountInfo {
+    #[serde(serialize_with = "serialize_display")]
     pub mint: Pubkey,
     pub amount: u64,
     pub decimals: u8,
@@ -88,8 +574,22 @@ pub struct TokenAccountInfo {
     pub ui_amount: f64,
 }
 
-#[derive(Debug, Clone)]
+/// Mint of wrapped SOL, an SPL token backed 1:1 by lamports held in the token account.
+pub const WRAPPED_SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// Metaplex Token Metadata program, which owns the name/symbol account of most SPL mints.
+pub const TOKEN_METADATA_PROGRAM: Pubkey =
+    solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+impl TokenAccountInfo {
+    pub fn is_wrapped_sol(&self) -> bool {
+        self.mint.to_string() == WRAPPED_SOL_MINT
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct TransactionSummary {
+    #[serde(serialize_with = "serialize_display")]
     pub signature: Signature,
     pub slot: u64,
     pub timestamp: Option<DateTime<Utc>>,
@@ -98,64 +598,37 @@ pub struct TransactionSummary {
     pub description: String,
 }
 
-// Known program IDs and their names
+/// Serializes pubkeys and signatures as base58 strings rather than raw byte arrays.
+fn serialize_display<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+fn serialize_display_seq<T: Display, S: Serializer>(
+    values: &[T],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(values.iter().map(|value| value.to_string()))
+}
+
+fn serialize_display_opt<T: Display, S: Serializer>(
+    value: &Option<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => serializer.collect_str(value),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_hex<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    serializer.serialize_str(&hex)
+}
+
+// Known program IDs and their names, including any user label files
 pub fn get_program_name(program_id: &Pubkey) -> Option<&'static str> {
-    const KNOWN_PROGRAMS: &[(&str, &str)] = &[
-        ("11111111111111111111111111111111", "System Program"),
-        (
-            "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
-            "Token Program",
-        ),
-        (
-            "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCQbphWkTg",
-            "Token-2022 Program",
-        ),
-        (
-            "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL",
-            "Associated Token Account",
-        ),
-        (
-            " ComputeBudget111111111111111111111111111111",
-            "Compute Budget",
-        ),
-        (
-            "Config1111111111111111111111111111111111111",
-            "Config Program",
-        ),
-        (
-            "Stake11111111111111111111111111111111111111",
-            "Stake Program",
-        ),
-        (
-            "Vote111111111111111111111111111111111111111",
-            "Vote Program",
-        ),
-        (
-            "AddressLookupTab1e1111111111111111111111111",
-            "Address Lookup Table",
-        ),
-        (
-            "BPFLoaderUpgradeab1e11111111111111111111111",
-            "BPF Loader Upgradeable",
-        ),
-        ("BPFLoader2111111111111111111111111111111111", "BPF Loader"),
-        (
-            "BPFLoader1111111111111111111111111111111111",
-            "BPF Loader (Legacy)",
-        ),
-        (
-            "Ed25519SigVerify111111111111111111111111111",
-            "Ed25519 SigVerify",
-        ),
-        (
-            "KeccakSecp256k11111111111111111111111111111",
-            "Secp256k1 Program",
-        ),
-    ];
-
-    let program_id_str = program_id.to_string();
-    KNOWN_PROGRAMS
-        .iter()
-        .find(|(id, _)| *id == program_id_str)
-        .map(|(_, name)| *name)
+    super::labels::get_address_label(program_id)
 }
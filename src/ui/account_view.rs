@@ -1,20 +1,34 @@
-use crate::app::App;
-use crate::solana::types::{AccountData, TransactionStatus};
+use crate::app::{AccountPane, App, BalanceUnit};
+use crate::solana::types::{
+    AccountData, MintAccountInfo, StakeAccountInfo, StakeStatus, TransactionStatus, VoteAccountInfo,
+};
 use crate::ui::styles::*;
-use crate::ui::{format_sol, truncate_pubkey};
+use crate::ui::{
+    context_title, format_balance, highlight_line, relative_time, scroll_markers, status_title,
+    truncate_pubkey,
+};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{block::Title, Block, Borders, Paragraph, Wrap},
     Frame,
 };
+use solana_sdk::pubkey::Pubkey;
 
 pub fn draw(f: &mut Frame, app: &App) {
     let size = f.size();
-    let block = Block::default()
+    let mut block = Block::default()
         .title(" Account Details ")
+        .title(context_title(app))
         .borders(Borders::ALL)
-        .border_style(PRIMARY_STYLE);
+        .border_style(primary_style());
+    if let Some(status) = status_title(app) {
+        block = block.title(status);
+    }
+    if app.auto_refresh {
+        block = block.title(live_title(app));
+    }
     f.render_widget(block, size);
 
     if let Some(data) = &app.account_data {
@@ -22,98 +36,553 @@ pub fn draw(f: &mut Frame, app: &App) {
     } else {
         let no_data = Paragraph::new("No account data available")
             .alignment(ratatui::layout::Alignment::Center)
-            .style(ERROR_STYLE);
+            .style(error_style());
         f.render_widget(no_data, size);
     }
 }
 
+/// Top-right marker shown while auto-refresh is on, with the age of the last refresh.
+fn live_title(app: &App) -> Title<'static> {
+    let updated = app.last_refresh.map_or_else(
+        || "refreshing…".to_string(),
+        |last| format!("updated {}s ago", last.elapsed().as_secs()),
+    );
+    Title::from(vec![
+        Span::styled(" ● live ", success_style()),
+        Span::styled(format!("· {} · a to stop ", updated), dim_style()),
+    ])
+    .alignment(Alignment::Right)
+}
+
+/// Token accounts shown before the pane starts scrolling.
+const MAX_TOKEN_ROWS: usize = 10;
+
 fn draw_account_content(f: &mut Frame, data: &AccountData, app: &App, area: Rect) {
     let inner = area.inner(&ratatui::layout::Margin {
         horizontal: 1,
         vertical: 1,
     });
 
+    let overview = account_overview_lines(data, app.balance_unit, app.selected_network.name());
+    // Decoded state of vote, stake and mint accounts gets its own section under the overview
+    let details = data
+        .vote_state
+        .as_ref()
+        .map(|vote| (" Vote Account ", vote_account_lines(vote)))
+        .or_else(|| {
+            let stake = data.stake_state.as_ref()?;
+            Some((
+                " Stake Account ",
+                stake_account_lines(stake, app.balance_unit),
+            ))
+        })
+        .or_else(|| {
+            let mint = data.mint_state.as_ref()?;
+            Some((" Token Mint ", mint_account_lines(mint)))
+        });
+    let token_rows = data.token_accounts.len().clamp(1, MAX_TOKEN_ROWS);
+
+    let mut constraints = vec![Constraint::Length(overview.len() as u16 + 2)];
+    if let Some((_, lines)) = &details {
+        constraints.push(Constraint::Length(lines.len() as u16 + 2));
+    }
+    if app.show_hex_dump {
+        constraints.push(Constraint::Min(0));
+    } else {
+        constraints.push(Constraint::Length(token_rows as u16 + 2));
+        constraints.push(Constraint::Min(0));
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(7),
-            Constraint::Length(8),
-            Constraint::Min(0),
-        ])
+        .constraints(constraints)
         .split(inner);
 
-    draw_account_overview(f, data, chunks[0]);
-    draw_token_accounts(f, data, chunks[1]);
-    draw_transaction_history(f, data, app.account_scroll, chunks[2]);
-}
+    draw_section(f, " Overview ", overview, chunks[0]);
+    if let Some((title, lines)) = details {
+        draw_section(f, title, lines, chunks[1]);
+    }
 
-fn draw_account_overview(f: &mut Frame, data: &AccountData, area: Rect) {
-    let block = Block::default()
-        .title(" Overview ")
-        .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+    if app.show_hex_dump {
+        let hex_scroll_max = draw_hex_dump(f, data, app.hex_scroll, chunks[chunks.len() - 1]);
+        app.hex_scroll_max.set(hex_scroll_max);
+        return;
+    }
 
-    let account_type = if data.executable {
-        "Program (Executable)"
-    } else if data.data_size == 0 {
-        "System Account"
+    let (token_area, history_area) = (chunks[chunks.len() - 2], chunks[chunks.len() - 1]);
+    let token_scroll_max = draw_token_accounts(
+        f,
+        data,
+        app.token_scroll,
+        app.balance_unit,
+        pane_style(app, AccountPane::TokenAccounts),
+        token_area,
+    );
+    app.token_scroll_max.set(token_scroll_max);
+    draw_transaction_history(
+        f,
+        data,
+        app.history_selected,
+        app.balance_unit,
+        pane_style(app, AccountPane::History),
+        history_area,
+    );
+}
+
+/// Border style for a pane, highlighting the one that scroll keys act on.
+fn pane_style(app: &App, pane: AccountPane) -> Style {
+    if app.account_pane == pane {
+        primary_style()
     } else {
-        "Data Account"
-    };
+        secondary_style()
+    }
+}
 
+fn account_overview_lines(
+    data: &AccountData,
+    unit: BalanceUnit,
+    network: &str,
+) -> Vec<Line<'static>> {
     let pubkey_str = data.pubkey.to_string();
+    if !data.exists {
+        return missing_account_lines(data, pubkey_str, network);
+    }
     let owner_str = data.owner.to_string();
 
-    let text = vec![
+    let mut lines = vec![
         Line::from(vec![
-            Span::styled("Address: ", HEADER_STYLE),
-            Span::raw(&pubkey_str),
+            Span::styled("Address: ", header_style()),
+            Span::raw(pubkey_str),
         ]),
         Line::from(vec![
-            Span::styled("Balance: ", HEADER_STYLE),
-            Span::styled(format_sol(data.lamports), SUCCESS_STYLE),
+            Span::styled("Balance: ", header_style()),
+            Span::styled(format_balance(data.lamports, unit), success_style()),
         ]),
         Line::from(vec![
-            Span::styled("Owner: ", HEADER_STYLE),
+            Span::styled("Owner: ", header_style()),
             Span::raw(truncate_pubkey(&owner_str)),
         ]),
         Line::from(vec![
-            Span::styled("Type: ", HEADER_STYLE),
-            Span::raw(account_type),
+            Span::styled("Type: ", header_style()),
+            Span::styled(
+                data.account_type.clone(),
+                if data.exists {
+                    text_style()
+                } else {
+                    warning_style()
+                },
+            ),
             Span::raw("  Data Size: "),
             Span::raw(format!("{} bytes", data.data_size)),
         ]),
     ];
 
+    if let Some(min) = data.min_balance_for_rent_exemption {
+        let (verdict, style) = if data.is_rent_exempt {
+            ("Yes", success_style())
+        } else {
+            ("No", warning_style())
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Rent exempt: ", header_style()),
+            Span::styled(verdict, style),
+            Span::styled(format!(" (min {})", format_balance(min, unit)), dim_style()),
+        ]));
+    }
+
+    if let Some(activity) = activity_sparkline(data) {
+        lines.push(activity);
+    }
+
+    lines
+}
+
+/// In place of the overview for an address with no account, which may never have been
+/// funded or may have been closed. Closed accounts keep their signature history.
+fn missing_account_lines(data: &AccountData, pubkey: String, network: &str) -> Vec<Line<'static>> {
+    let history = if data.recent_transactions.is_empty() {
+        "No transactions have touched this address either".to_string()
+    } else {
+        "Its past transactions are listed below".to_string()
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Address: ", header_style()),
+            Span::raw(pubkey),
+        ]),
+        Line::from(Span::styled(
+            format!("Account does not exist or has been closed on {}", network),
+            warning_style(),
+        )),
+        Line::from(Span::styled(history, dim_style())),
+    ];
+    if let Some(activity) = activity_sparkline(data) {
+        lines.push(activity);
+    }
+    lines
+}
+
+/// Number of time buckets in the activity sparkline.
+const SPARKLINE_BUCKETS: usize = 24;
+
+/// Recent transactions per time bucket, from oldest to newest, drawn with block characters.
+fn activity_sparkline(data: &AccountData) -> Option<Line<'static>> {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let times: Vec<i64> = data
+        .recent_transactions
+        .iter()
+        .filter_map(|txn| txn.timestamp.map(|t| t.timestamp()))
+        .collect();
+    if times.len() < 2 {
+        return None;
+    }
+    let (oldest, newest) = (*times.iter().min()?, *times.iter().max()?);
+
+    let span = (newest - oldest).max(1);
+    let mut buckets = [0usize; SPARKLINE_BUCKETS];
+    for t in &times {
+        let bucket = ((t - oldest) * (SPARKLINE_BUCKETS as i64 - 1) / span) as usize;
+        buckets[bucket] += 1;
+    }
+
+    let peak = buckets.iter().copied().max().unwrap_or(1);
+    let sparkline: String = buckets
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                ' '
+            } else {
+                BARS[count * (BARS.len() - 1) / peak]
+            }
+        })
+        .collect();
+
+    Some(Line::from(vec![
+        Span::styled("Activity: ", header_style()),
+        Span::styled(sparkline, success_style()),
+        Span::styled(
+            format!(" {} txs over {}", times.len(), format_span(span)),
+            dim_style(),
+        ),
+    ]))
+}
+
+fn format_span(seconds: i64) -> String {
+    match seconds {
+        s if s < 3_600 => format!("{}m", s.max(60) / 60),
+        s if s < 86_400 => format!("{}h", s / 3_600),
+        s => format!("{}d", s / 86_400),
+    }
+}
+
+fn vote_account_lines(vote: &VoteAccountInfo) -> Vec<Line<'static>> {
+    let slot = |slot: Option<u64>| slot.map_or_else(|| "None".to_string(), |s| s.to_string());
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Node: ", header_style()),
+            Span::raw(vote.node_pubkey.to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("Authorized Voter: ", header_style()),
+            Span::raw(truncate_pubkey(&vote.authorized_voter.to_string())),
+            Span::styled("  Withdrawer: ", header_style()),
+            Span::raw(truncate_pubkey(&vote.authorized_withdrawer.to_string())),
+        ]),
+        Line::from(vec![
+            Span::styled("Last Vote: ", header_style()),
+            Span::raw(slot(vote.last_voted_slot)),
+            Span::styled("  Root Slot: ", header_style()),
+            Span::raw(slot(vote.root_slot)),
+            Span::styled("  Commission: ", header_style()),
+            Span::raw(format!("{}%", vote.commission)),
+        ]),
+    ];
+
+    let mut credits = vec![Span::styled("Credits: ", header_style())];
+    if vote.epoch_credits.is_empty() {
+        credits.push(Span::styled("none yet", dim_style()));
+    }
+    for (i, epoch) in vote.epoch_credits.iter().enumerate() {
+        if i > 0 {
+            credits.push(Span::styled(" | ", dim_style()));
+        }
+        credits.push(Span::styled(format!("E{} ", epoch.epoch), dim_style()));
+        credits.push(Span::styled(epoch.credits.to_string(), success_style()));
+    }
+    lines.push(Line::from(credits));
+
+    lines
+}
+
+fn stake_account_lines(stake: &StakeAccountInfo, unit: BalanceUnit) -> Vec<Line<'static>> {
+    let mut state = vec![
+        Span::styled("State: ", header_style()),
+        Span::raw(capitalize(&stake.state)),
+    ];
+    let Some(delegation) = &stake.delegation else {
+        state.push(Span::styled("  (not delegated)", dim_style()));
+        return vec![Line::from(state), authority_line(stake)];
+    };
+
+    if let Some(status) = delegation.status {
+        let style = match status {
+            StakeStatus::Active => success_style(),
+            StakeStatus::Activating | StakeStatus::Deactivating => warning_style(),
+            StakeStatus::Inactive => dim_style(),
+        };
+        state.push(Span::styled("  Status: ", header_style()));
+        state.push(Span::styled(status.name(), style));
+    }
+
+    let mut stake_line = vec![
+        Span::styled("Delegated Stake: ", header_style()),
+        Span::raw(format_balance(delegation.stake, unit)),
+    ];
+    if let Some(active) = delegation.active_stake() {
+        stake_line.push(Span::styled("  Active: ", header_style()));
+        stake_line.push(Span::styled(format_balance(active, unit), success_style()));
+    }
+
+    let mut epochs = vec![
+        Span::styled("Activation Epoch: ", header_style()),
+        Span::raw(delegation.activation_epoch.to_string()),
+    ];
+    if let Some(epoch) = delegation.deactivation_epoch {
+        epochs.push(Span::styled("  Deactivation Epoch: ", header_style()));
+        epochs.push(Span::raw(epoch.to_string()));
+    }
+
+    vec![
+        Line::from(state),
+        Line::from(vec![
+            Span::styled("Delegated To: ", header_style()),
+            Span::raw(delegation.voter.to_string()),
+        ]),
+        Line::from(stake_line),
+        Line::from(epochs),
+        authority_line(stake),
+    ]
+}
+
+fn mint_account_lines(mint: &MintAccountInfo) -> Vec<Line<'static>> {
+    let authority = |authority: Option<Pubkey>, none: &'static str| {
+        authority.map_or_else(
+            || Span::styled(none, dim_style()),
+            |pubkey| Span::raw(pubkey.to_string()),
+        )
+    };
+    let name = mint
+        .token_name
+        .clone()
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Token: ", header_style()),
+            Span::raw(name.clone()),
+            Span::styled("  Decimals: ", header_style()),
+            Span::raw(mint.decimals.to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("Supply: ", header_style()),
+            Span::styled(
+                format!("{:.*}", mint.decimals as usize, mint.ui_supply()),
+                success_style(),
+            ),
+            Span::raw(format!(" {}", name)),
+        ]),
+        Line::from(vec![
+            Span::styled("Mint Authority: ", header_style()),
+            authority(mint.mint_authority, "none (supply is fixed)"),
+        ]),
+        Line::from(vec![
+            Span::styled("Freeze Authority: ", header_style()),
+            authority(mint.freeze_authority, "none"),
+        ]),
+    ];
+
+    if !mint.extensions.is_empty() {
+        let names: Vec<String> = mint
+            .extensions
+            .iter()
+            .map(|ext| split_camel_case(ext))
+            .collect();
+        lines.push(Line::from(vec![
+            Span::styled("Extensions: ", header_style()),
+            Span::raw(names.join(", ")),
+        ]));
+    }
+
+    lines
+}
+
+/// `transferFeeConfig` -> `Transfer Fee Config`, for Token-2022 extension names.
+fn split_camel_case(name: &str) -> String {
+    let mut words = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if i == 0 {
+            words.extend(c.to_uppercase());
+        } else {
+            if c.is_uppercase() {
+                words.push(' ');
+            }
+            words.push(c);
+        }
+    }
+    words
+}
+
+fn authority_line(stake: &StakeAccountInfo) -> Line<'static> {
+    Line::from(vec![
+        Span::styled("Staker: ", header_style()),
+        Span::raw(truncate_pubkey(&stake.staker.to_string())),
+        Span::styled("  Withdrawer: ", header_style()),
+        Span::raw(truncate_pubkey(&stake.withdrawer.to_string())),
+    ])
+}
+
+/// `delegated` -> `Delegated`, for the RPC's lowercase state names.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn draw_section(f: &mut Frame, title: &str, text: Vec<Line>, area: Rect) {
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(secondary_style());
+
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(TEXT_STYLE)
+        .style(text_style())
         .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
 }
 
-fn draw_token_accounts(f: &mut Frame, data: &AccountData, area: Rect) {
+/// Bytes per hex dump row.
+const HEX_ROW_BYTES: usize = 16;
+
+/// Classic hex dump of the account data: offset, 16 hex bytes and an ASCII gutter.
+/// Only the visible rows are formatted, so large program accounts stay cheap to draw.
+/// Returns the largest scroll offset that still fills the pane.
+fn draw_hex_dump(f: &mut Frame, data: &AccountData, scroll: usize, area: Rect) -> usize {
+    let rows = data.data.len().div_ceil(HEX_ROW_BYTES);
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let max_scroll = rows.saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
+    let block = Block::default()
+        .title(format!(" Data ({} bytes) ", data.data.len()))
+        .title(scroll_markers(scroll, rows, visible_lines))
+        .borders(Borders::ALL)
+        .border_style(primary_style());
+
+    let text: Vec<Line> = if data.data.is_empty() {
+        vec![Line::from("Account has no data")]
+    } else {
+        data.data
+            .chunks(HEX_ROW_BYTES)
+            .enumerate()
+            .skip(scroll)
+            .take(visible_lines)
+            .map(|(row, bytes)| hex_dump_line(row * HEX_ROW_BYTES, bytes))
+            .collect()
+    };
+
+    let paragraph = Paragraph::new(text).block(block).style(text_style());
+    f.render_widget(paragraph, area);
+    max_scroll
+}
+
+fn hex_dump_line(offset: usize, bytes: &[u8]) -> Line<'static> {
+    let mut hex = String::with_capacity(HEX_ROW_BYTES * 3 + 1);
+    for i in 0..HEX_ROW_BYTES {
+        if i == HEX_ROW_BYTES / 2 {
+            hex.push(' ');
+        }
+        match bytes.get(i) {
+            Some(b) => hex.push_str(&format!("{:02x} ", b)),
+            None => hex.push_str("   "),
+        }
+    }
+    let ascii: String = bytes
+        .iter()
+        .map(|&b| {
+            if b.is_ascii_graphic() || b == b' ' {
+                b as char
+            } else {
+                '.'
+            }
+        })
+        .collect();
+
+    Line::from(vec![
+        Span::styled(format!("{:08x}  ", offset), dim_style()),
+        Span::raw(hex),
+        Span::styled(format!(" |{}|", ascii), secondary_style()),
+    ])
+}
+
+/// Returns the largest scroll offset that still fills the pane.
+fn draw_token_accounts(
+    f: &mut Frame,
+    data: &AccountData,
+    scroll: usize,
+    unit: BalanceUnit,
+    border_style: Style,
+    area: Rect,
+) -> usize {
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let max_scroll = data.token_accounts.len().saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
     let block = Block::default()
         .title(format!(" Token Accounts ({}) ", data.token_accounts.len()))
+        .title(scroll_markers(
+            scroll,
+            data.token_accounts.len(),
+            visible_lines,
+        ))
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(border_style);
 
     let mut text: Vec<Line> = Vec::new();
 
     if data.token_accounts.is_empty() {
         text.push(Line::from("No token accounts found"));
     } else {
-        for (i, token) in data.token_accounts.iter().enumerate() {
+        let visible = data
+            .token_accounts
+            .iter()
+            .enumerate()
+            .skip(scroll)
+            .take(visible_lines);
+
+        for (i, token) in visible {
+            if token.is_wrapped_sol() {
+                text.push(Line::from(vec![
+                    Span::styled(format!("{}. ", i + 1), dim_style()),
+                    Span::raw("Wrapped SOL: "),
+                    Span::styled(format_balance(token.amount, unit), success_style()),
+                    Span::styled(" (close the account to unwrap)", dim_style()),
+                ]));
+                continue;
+            }
+
             let amount = token.amount as f64 / 10f64.powi(token.decimals as i32);
             let name = token.token_name.as_deref().unwrap_or("Unknown");
             let mint_str = token.mint.to_string();
 
             text.push(Line::from(vec![
-                Span::styled(format!("{}. ", i + 1), DIM_STYLE),
+                Span::styled(format!("{}. ", i + 1), dim_style()),
                 Span::raw(name),
                 Span::raw(": "),
-                Span::styled(format!("{:.6}", amount), SUCCESS_STYLE),
+                Span::styled(format!("{:.6}", amount), success_style()),
                 Span::raw(" ("),
                 Span::raw(truncate_pubkey(&mint_str)),
                 Span::raw(")"),
@@ -123,62 +592,104 @@ fn draw_token_accounts(f: &mut Frame, data: &AccountData, area: Rect) {
 
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(TEXT_STYLE)
+        .style(text_style())
         .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
+    max_scroll
 }
 
-fn draw_transaction_history(f: &mut Frame, data: &AccountData, scroll: usize, area: Rect) {
+fn draw_transaction_history(
+    f: &mut Frame,
+    data: &AccountData,
+    selected: usize,
+    unit: BalanceUnit,
+    border_style: Style,
+    area: Rect,
+) {
+    let more = if data.history_before.is_some() {
+        " · n: load more"
+    } else {
+        ""
+    };
     let block = Block::default()
         .title(format!(
-            " Recent Transactions ({}) ",
-            data.recent_transactions.len()
+            " Recent Transactions ({}{}) ",
+            data.recent_transactions.len(),
+            more
         ))
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(border_style);
+
+    // Keep the selected row in view
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let scroll = (selected + 1).saturating_sub(visible_lines);
+    let block = block.title(scroll_markers(
+        scroll,
+        data.recent_transactions.len(),
+        visible_lines,
+    ));
 
     let mut text: Vec<Line> = Vec::new();
 
     if data.recent_transactions.is_empty() {
         text.push(Line::from("No recent transactions"));
     } else {
-        let visible: Vec<_> = data
+        let visible = data
             .recent_transactions
             .iter()
+            .enumerate()
             .skip(scroll)
-            .take(area.height as usize - 2)
-            .collect();
+            .take(visible_lines);
 
-        for txn in visible {
+        for (i, txn) in visible {
             let time_str = txn
                 .timestamp
                 .as_ref()
-                .map(|t| t.format("%m/%d %H:%M").to_string())
+                .map(|t| format!("{} ({})", t.format("%m/%d %H:%M"), relative_time(*t)))
                 .unwrap_or_else(|| "Unknown".to_string());
 
             let status_symbol = match &txn.status {
-                TransactionStatus::Success => Span::styled("✓", SUCCESS_STYLE),
-                TransactionStatus::Failed(_) => Span::styled("✗", ERROR_STYLE),
+                TransactionStatus::Success => Span::styled("✓", success_style()),
+                TransactionStatus::Failed(_) => Span::styled("✗", error_style()),
             };
 
             let sig_str = txn.signature.to_string();
 
-            text.push(Line::from(vec![
+            let mut spans = vec![
                 status_symbol,
                 Span::raw(" "),
-                Span::styled(time_str, DIM_STYLE),
+                Span::styled(time_str, dim_style()),
                 Span::raw(" Slot "),
                 Span::raw(txn.slot.to_string()),
                 Span::raw(" "),
                 Span::raw(truncate_pubkey(&sig_str)),
-            ]));
+            ];
+            if txn.fee > 0 {
+                spans.push(Span::styled(
+                    format!(" fee {}", format_balance(txn.fee, unit)),
+                    dim_style(),
+                ));
+            }
+            if !txn.description.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", txn.description),
+                    primary_style(),
+                ));
+            }
+            let line = Line::from(spans);
+
+            text.push(if i == selected {
+                highlight_line(line)
+            } else {
+                line
+            });
         }
     }
 
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(TEXT_STYLE)
+        .style(text_style())
         .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
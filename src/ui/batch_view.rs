@@ -0,0 +1,103 @@
+use crate::app::App;
+use crate::solana::summary;
+use crate::solana::types::TransactionStatus;
+use crate::ui::styles::*;
+use crate::ui::{context_title, highlight_line, scroll_markers, status_title, truncate_pubkey};
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+pub fn draw(f: &mut Frame, app: &App) {
+    let size = f.size();
+    let mut block = Block::default()
+        .title(" Batch Lookup ")
+        .title(context_title(app))
+        .borders(Borders::ALL)
+        .border_style(primary_style());
+    if let Some(status) = status_title(app) {
+        block = block.title(status);
+    }
+    f.render_widget(block, size);
+
+    let inner = size.inner(&ratatui::layout::Margin {
+        horizontal: 1,
+        vertical: 1,
+    });
+    draw_batch_list(f, app, inner);
+}
+
+/// One row per signature, in the order entered, with the selected row kept in view.
+fn draw_batch_list(f: &mut Frame, app: &App, area: Rect) {
+    let failed = app
+        .batch
+        .iter()
+        .filter(|entry| entry.result.is_err())
+        .count();
+    let failed = if failed > 0 {
+        format!(", {} not fetched", failed)
+    } else {
+        String::new()
+    };
+    let block = Block::default()
+        .title(format!(
+            " Transactions ({}{}) · Enter: open ",
+            app.batch.len(),
+            failed
+        ))
+        .borders(Borders::ALL)
+        .border_style(secondary_style());
+
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let scroll = (app.batch_selected + 1).saturating_sub(visible_lines);
+    let block = block.title(scroll_markers(scroll, app.batch.len(), visible_lines));
+
+    let text: Vec<Line> = app
+        .batch
+        .iter()
+        .enumerate()
+        .skip(scroll)
+        .take(visible_lines)
+        .map(|(i, entry)| {
+            let (marker, details) = match &entry.result {
+                Ok(data) => {
+                    let marker = match data.status {
+                        TransactionStatus::Success => Span::styled("✓", success_style()),
+                        TransactionStatus::Failed(_) => Span::styled("✗", error_style()),
+                    };
+                    let details = vec![
+                        Span::styled(
+                            summary::one_line_details(data, app.balance_unit),
+                            dim_style(),
+                        ),
+                        Span::styled(format!("  {}", data.summary), primary_style()),
+                    ];
+                    (marker, details)
+                }
+                Err(e) => (
+                    Span::styled("!", warning_style()),
+                    vec![Span::styled(format!("not fetched: {}", e), error_style())],
+                ),
+            };
+
+            let mut spans = vec![
+                marker,
+                Span::styled(format!(" {:>3}. ", i + 1), dim_style()),
+                Span::raw(truncate_pubkey(&entry.signature)),
+                Span::raw(" "),
+            ];
+            spans.extend(details);
+            let line = Line::from(spans);
+            if i == app.batch_selected {
+                highlight_line(line)
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(text).block(block).style(text_style());
+    f.render_widget(paragraph, area);
+}
@@ -1,7 +1,9 @@
 use crate::app::{App, InputType};
+use crate::ui::highlight_line;
 use crate::ui::styles::*;
+use crate::utils::validators;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
@@ -19,18 +21,26 @@ pub fn draw(f: &mut Frame, app: &App) {
             Constraint::Length(6),
             Constraint::Length(2),
             Constraint::Length(3),
+            Constraint::Min(0),
         ])
         .split(size);
 
     let title = Paragraph::new("Solana Transaction & Account Explorer")
         .alignment(Alignment::Center)
-        .style(HEADER_STYLE.add_modifier(Modifier::BOLD));
+        .style(header_style().add_modifier(Modifier::BOLD));
     f.render_widget(title, chunks[0]);
 
     let input_type = match app.get_input_type() {
-        InputType::Transaction => Span::styled("Transaction", SUCCESS_STYLE),
-        InputType::Account => Span::styled("Account", SUCCESS_STYLE),
-        InputType::Unknown => Span::styled("Unknown", ERROR_STYLE),
+        InputType::Transaction => Span::styled("Transaction", success_style()),
+        InputType::Account => Span::styled("Account", success_style()),
+        InputType::Batch => Span::styled(
+            format!(
+                "{} Transactions",
+                validators::batch_entries(&app.input).len()
+            ),
+            success_style(),
+        ),
+        InputType::Unknown => Span::styled("Unknown", error_style()),
     };
 
     let input_block = Block::default()
@@ -40,26 +50,72 @@ pub fn draw(f: &mut Frame, app: &App) {
             Span::raw(") "),
         ]))
         .borders(Borders::ALL)
-        .border_style(PRIMARY_STYLE);
+        .border_style(primary_style());
 
-    let input_text = Paragraph::new(app.input.as_str())
+    let input_line = if app.input.is_empty() {
+        Line::from(Span::styled(
+            "Enter a signature or address, or several signatures separated by commas...",
+            hint_style(),
+        ))
+    } else {
+        Line::from(app.input.as_str())
+    };
+
+    let input_text = Paragraph::new(input_line)
         .block(input_block)
-        .style(TEXT_STYLE);
+        .style(text_style());
     f.render_widget(input_text, chunks[2]);
 
-    let cursor_x = chunks[2].x + app.input_cursor as u16 + 1;
+    if let Some(reason) = app.invalid_input_reason() {
+        let hint = Paragraph::new(Span::styled(reason, error_style())).alignment(Alignment::Center);
+        f.render_widget(hint, chunks[3]);
+    }
+
+    let cursor_x = chunks[2].x + app.input_cursor_column() as u16 + 1;
     let cursor_y = chunks[2].y + 1;
     f.set_cursor(cursor_x, cursor_y);
 
     let hints = Paragraph::new(vec![Line::from(vec![
-        Span::styled("Enter", SELECTED_STYLE),
+        Span::styled("Enter", selected_style()),
         Span::raw(" to continue  "),
-        Span::styled("Ctrl+C", SELECTED_STYLE),
+        Span::styled("Tab", selected_style()),
+        Span::raw(" to choose network  "),
+        Span::styled("Ctrl+C", selected_style()),
         Span::raw(" or "),
-        Span::styled("Esc", SELECTED_STYLE),
+        Span::styled("Esc", selected_style()),
         Span::raw(" to quit"),
     ])])
     .alignment(Alignment::Center)
-    .style(HINT_STYLE);
+    .style(hint_style());
     f.render_widget(hints, chunks[4]);
+
+    draw_recent(f, app, chunks[5]);
+}
+
+/// Recently fetched items, browsed with the arrow keys.
+fn draw_recent(f: &mut Frame, app: &App, area: Rect) {
+    if app.recent_queries.is_empty() || area.height < 3 {
+        return;
+    }
+    let block = Block::default()
+        .title(" Recent (↑/↓ to pick) ")
+        .borders(Borders::ALL)
+        .border_style(secondary_style());
+
+    let lines: Vec<Line> = app
+        .recent_queries
+        .iter()
+        .enumerate()
+        .map(|(i, query)| {
+            let line = Line::from(query.as_str());
+            if app.recent_selected == Some(i) {
+                highlight_line(line)
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    let list = Paragraph::new(lines).block(block).style(text_style());
+    f.render_widget(list, area);
 }
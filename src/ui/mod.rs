@@ -1,40 +1,75 @@
 mod account_view;
+mod batch_view;
 mod input_screen;
 mod network_selection;
 mod styles;
 mod transaction_view;
 
+pub use crate::utils::format::{format_balance, format_balance_delta};
+pub use crate::utils::time::relative_time;
+pub use styles::Theme;
+
 use crate::app::{App, Screen};
+use crate::solana::labels;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    widgets::{
+        block::{Position, Title},
+        Block, Borders, Clear, Paragraph, Wrap,
+    },
     Frame,
 };
 
 pub fn draw(f: &mut Frame, app: &App) {
+    styles::set_theme(app.theme);
     match &app.screen {
         Screen::Input => input_screen::draw(f, app),
         Screen::NetworkSelection => network_selection::draw(f, app),
-        Screen::Loading => draw_loading(f),
+        Screen::Loading => draw_loading(f, app),
         Screen::Transaction => transaction_view::draw(f, app),
         Screen::Account => account_view::draw(f, app),
+        Screen::Batch => batch_view::draw(f, app),
         Screen::Error(msg) => draw_error(f, msg),
     }
+
+    if let Some(query) = &app.quick_search {
+        draw_quick_search(f, query);
+    }
+    if app.show_legend && matches!(app.screen, Screen::Transaction | Screen::Account) {
+        draw_legend(f);
+    }
+    if app.show_about {
+        draw_about(f, app);
+    }
 }
 
-fn draw_loading(f: &mut Frame) {
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+fn draw_loading(f: &mut Frame, app: &App) {
     let size = f.size();
     let block = Block::default()
         .title(" Solana TUI ")
         .borders(Borders::ALL)
-        .border_style(styles::PRIMARY_STYLE);
+        .border_style(styles::primary_style());
 
     f.render_widget(block, size);
 
-    let loading_text = Paragraph::new("Loading...")
-        .alignment(Alignment::Center)
-        .style(styles::TEXT_STYLE);
+    let spinner = SPINNER_FRAMES[app.loading_tick % SPINNER_FRAMES.len()];
+    let elapsed = app
+        .loading_started
+        .map_or(0, |started| started.elapsed().as_secs());
+
+    let loading_text = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(format!("{} ", spinner), styles::primary_style()),
+            Span::raw(format!("Loading... {}s", elapsed)),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Esc to cancel", styles::hint_style())),
+    ])
+    .alignment(Alignment::Center)
+    .style(styles::text_style());
 
     let area = centered_rect(30, 20, size);
     f.render_widget(Clear, area);
@@ -46,18 +81,18 @@ fn draw_error(f: &mut Frame, msg: &str) {
     let block = Block::default()
         .title(" Error ")
         .borders(Borders::ALL)
-        .border_style(styles::ERROR_STYLE);
+        .border_style(styles::error_style());
 
     f.render_widget(block, size);
 
     let error_text = Paragraph::new(vec![
-        Line::from(Span::styled("Error:", styles::ERROR_STYLE)),
+        Line::from(Span::styled("Error:", styles::error_style())),
         Line::from(""),
         Line::from(msg),
         Line::from(""),
         Line::from(Span::styled(
             "Press 'r' to return or 'q' to quit",
-            styles::HINT_STYLE,
+            styles::hint_style(),
         )),
     ])
     .alignment(Alignment::Center)
@@ -68,6 +103,208 @@ fn draw_error(f: &mut Frame, msg: &str) {
     f.render_widget(error_text, area);
 }
 
+/// One-line input over the bottom of a result screen, above its status border.
+fn draw_quick_search(f: &mut Frame, query: &str) {
+    let size = f.size();
+    if size.height < 5 || size.width < 4 {
+        return;
+    }
+    let area = Rect::new(1, size.height - 4, size.width - 2, 3);
+    let block = Block::default()
+        .title(" Go to signature or address ")
+        .title(
+            Title::from(Span::styled(
+                " Enter fetch · Esc close ",
+                styles::hint_style(),
+            ))
+            .alignment(Alignment::Right),
+        )
+        .borders(Borders::ALL)
+        .border_style(styles::primary_style());
+
+    let paragraph = Paragraph::new(Line::from(vec![
+        Span::styled("/ ", styles::header_style()),
+        Span::raw(query.to_string()),
+    ]))
+    .block(block)
+    .style(styles::text_style());
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+    f.set_cursor(area.x + 3 + query.chars().count() as u16, area.y + 1);
+}
+
+fn draw_legend(f: &mut Frame) {
+    let area = centered_rect(50, 50, f.size());
+    let block = Block::default()
+        .title(" Legend ")
+        .borders(Borders::ALL)
+        .border_style(styles::primary_style());
+
+    let text = vec![
+        Line::from(vec![
+            Span::styled("S  ", styles::header_style()),
+            Span::raw("Signer"),
+        ]),
+        Line::from(vec![
+            Span::styled("W  ", styles::header_style()),
+            Span::raw("Writable"),
+        ]),
+        Line::from(vec![
+            Span::styled("L  ", styles::header_style()),
+            Span::raw("Loaded from an address lookup table"),
+        ]),
+        Line::from(vec![
+            Span::styled("✓  ", styles::success_style()),
+            Span::raw("Transaction succeeded"),
+        ]),
+        Line::from(vec![
+            Span::styled("✗  ", styles::error_style()),
+            Span::raw("Transaction failed"),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("(+0.1 SOL)", styles::success_style()),
+            Span::raw(" balance increased"),
+        ]),
+        Line::from(vec![
+            Span::styled("(-0.1 SOL)", styles::error_style()),
+            Span::raw(" balance decreased"),
+        ]),
+        Line::from(vec![
+            Span::styled("(no change)", styles::dim_style()),
+            Span::raw(" balance unchanged"),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Press any key to close", styles::hint_style())),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .style(styles::text_style())
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+fn draw_about(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 50, f.size());
+    let block = Block::default()
+        .title(" About ")
+        .borders(Borders::ALL)
+        .border_style(styles::primary_style());
+
+    let mut text = vec![
+        Line::from(vec![
+            Span::styled("solana-txn-tui ", styles::header_style()),
+            Span::raw(format!("v{}", env!("CARGO_PKG_VERSION"))),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("RPC endpoint: ", styles::header_style()),
+            Span::raw(app.selected_network.url().to_string()),
+        ]),
+        Line::from(vec![
+            Span::styled("Commitment: ", styles::header_style()),
+            Span::raw(app.commitment.name().to_string()),
+            Span::styled("  Timeout: ", styles::header_style()),
+            Span::raw(format!("{}s", app.rpc_timeout.as_secs())),
+        ]),
+        Line::from(Span::styled("Label files:", styles::header_style())),
+    ];
+
+    let label_files = labels::loaded_files();
+    if label_files.is_empty() {
+        text.push(Line::from(Span::styled(
+            "  built-in only",
+            styles::dim_style(),
+        )));
+    }
+    for path in label_files {
+        text.push(Line::from(format!("  {}", path.display())));
+    }
+
+    text.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("By {}", env!("CARGO_PKG_AUTHORS")),
+            styles::dim_style(),
+        )),
+        Line::from(Span::styled(
+            env!("CARGO_PKG_REPOSITORY"),
+            styles::dim_style(),
+        )),
+        Line::from(""),
+        Line::from(Span::styled("Press any key to close", styles::hint_style())),
+    ]);
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .style(styles::text_style())
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Bottom-border title carrying the transient status message, if any.
+pub fn status_title(app: &App) -> Option<Title<'static>> {
+    let msg = app.active_status()?;
+    let style = if msg.is_error {
+        styles::error_style()
+    } else {
+        styles::success_style()
+    };
+
+    Some(
+        Title::from(Span::styled(format!(" {} ", msg.text), style))
+            .position(Position::Bottom)
+            .alignment(Alignment::Left),
+    )
+}
+
+/// Bottom-right title showing the active network and commitment, plus how many views
+/// Backspace can step back through.
+pub fn context_title(app: &App) -> Title<'static> {
+    let back = match app.nav_stack.len() {
+        0 => String::new(),
+        depth => format!(" ← {} |", depth),
+    };
+    Title::from(Span::styled(
+        format!(
+            "{} {} | {} ",
+            back,
+            app.selected_network.name(),
+            app.commitment.name()
+        ),
+        styles::dim_style(),
+    ))
+    .position(Position::Bottom)
+    .alignment(Alignment::Right)
+}
+
+/// Right-aligned ▲/▼ markers for a pane with content hidden above or below the viewport.
+pub fn scroll_markers(scroll: usize, total: usize, visible: usize) -> Title<'static> {
+    let marker = match (scroll > 0, scroll + visible < total) {
+        (true, true) => " ▲▼ ",
+        (true, false) => " ▲ ",
+        (false, true) => " ▼ ",
+        (false, false) => "",
+    };
+    Title::from(Span::styled(marker, styles::hint_style())).alignment(Alignment::Right)
+}
+
+/// Restyles a row with `selected_style()` to mark it as the current selection.
+pub fn highlight_line(line: Line) -> Line {
+    Line::from(
+        line.spans
+            .into_iter()
+            .map(|span| Span::styled(span.content, styles::selected_style()))
+            .collect::<Vec<_>>(),
+    )
+}
+
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -88,14 +325,32 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-pub fn format_sol(lamports: u64) -> String {
-    format!("{:.9} SOL", lamports as f64 / 1_000_000_000.0)
-}
-
 pub fn truncate_pubkey(pubkey: &str) -> String {
-    if pubkey.len() > 16 {
-        format!("{}...{}", &pubkey[..8], &pubkey[pubkey.len() - 8..])
+    // Count chars rather than bytes so text that isn't a base58 key can't split a char
+    let len = pubkey.chars().count();
+    if len > 16 {
+        let head: String = pubkey.chars().take(8).collect();
+        let tail: String = pubkey.chars().skip(len - 8).collect();
+        format!("{}...{}", head, tail)
     } else {
         pubkey.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncate_pubkey_counts_chars_not_bytes() {
+        // 17 chars but far more bytes, so byte slicing would split a char
+        assert_eq!(truncate_pubkey("ééééééééééééééééé"), "éééééééé...éééééééé");
+        // Short in chars but over 16 bytes, which used to slice mid-char
+        assert_eq!(truncate_pubkey("✓✓✓✓✓✓"), "✓✓✓✓✓✓");
+        assert_eq!(truncate_pubkey(""), "");
+        assert_eq!(
+            truncate_pubkey("11111111111111111111111111111111"),
+            "11111111...11111111"
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::app::{App, InputType};
 use crate::solana::Network;
 use crate::ui::styles::*;
+use crate::utils::validators;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     text::{Line, Span},
@@ -20,6 +21,7 @@ pub fn draw(f: &mut Frame, app: &App) {
             Constraint::Length(1),
             Constraint::Length(5),
             Constraint::Length(3),
+            Constraint::Length(4), // Custom RPC URL
             Constraint::Min(0),
             Constraint::Length(3),
         ])
@@ -27,32 +29,36 @@ pub fn draw(f: &mut Frame, app: &App) {
 
     let title = Paragraph::new("Solana Transaction & Account Explorer")
         .alignment(Alignment::Center)
-        .style(HEADER_STYLE);
+        .style(header_style());
     f.render_widget(title, chunks[0]);
 
     // Show what was entered
     let input_type = match app.get_input_type() {
-        InputType::Transaction => "Transaction",
-        InputType::Account => "Account",
-        InputType::Unknown => "Unknown",
+        InputType::Transaction => "Transaction".to_string(),
+        InputType::Account => "Account".to_string(),
+        InputType::Batch => format!(
+            "{} Transactions",
+            validators::batch_entries(&app.input).len()
+        ),
+        InputType::Unknown => "Unknown".to_string(),
     };
 
     let input_display = Paragraph::new(vec![
         Line::from(vec![
             Span::raw("Entered "),
-            Span::styled(input_type, SUCCESS_STYLE),
+            Span::styled(input_type, success_style()),
             Span::raw(":"),
         ]),
         Line::from(app.input.as_str()),
     ])
     .alignment(Alignment::Center)
-    .style(TEXT_STYLE);
+    .style(text_style());
     f.render_widget(input_display, chunks[2]);
 
     // Network selection prompt
     let prompt = Paragraph::new("Select Network:")
         .alignment(Alignment::Center)
-        .style(TEXT_STYLE);
+        .style(text_style());
     f.render_widget(prompt, chunks[4]);
 
     // Network buttons - compact horizontal layout
@@ -60,15 +66,16 @@ pub fn draw(f: &mut Frame, app: &App) {
         .direction(Direction::Horizontal)
         .margin(1)
         .constraints([
+            Constraint::Percentage(10),
             Constraint::Percentage(20),
             Constraint::Percentage(20),
             Constraint::Percentage(20),
             Constraint::Percentage(20),
-            Constraint::Percentage(20),
+            Constraint::Percentage(10),
         ])
         .split(chunks[5]);
 
-    // Center the network selectors by using indices 1, 2, 3
+    // Center the network selectors by using indices 1 to 4
     draw_network_button(
         f,
         Network::Mainnet,
@@ -87,25 +94,70 @@ pub fn draw(f: &mut Frame, app: &App) {
         app.selected_network == Network::Testnet,
         network_chunks[3],
     );
+    draw_network_button(
+        f,
+        Network::Custom(String::new()),
+        matches!(app.selected_network, Network::Custom(_)),
+        network_chunks[4],
+    );
+
+    let back_key = if let Network::Custom(url) = &app.selected_network {
+        draw_custom_url(f, url, chunks[6]);
+        "Esc"
+    } else {
+        "Backspace"
+    };
 
     // Hints
     let hints = Paragraph::new(vec![Line::from(vec![
-        Span::styled("←/→", SELECTED_STYLE),
+        Span::styled("←/→", selected_style()),
         Span::raw(" or "),
-        Span::styled("↑/↓", SELECTED_STYLE),
+        Span::styled("↑/↓", selected_style()),
         Span::raw(" to change  "),
-        Span::styled("Enter", SELECTED_STYLE),
+        Span::styled("Enter", selected_style()),
         Span::raw(" to confirm  "),
-        Span::styled("Backspace", SELECTED_STYLE),
+        Span::styled(back_key, selected_style()),
         Span::raw(" to go back"),
     ])])
     .alignment(Alignment::Center)
-    .style(HINT_STYLE);
-    f.render_widget(hints, chunks[7]);
+    .style(hint_style());
+    f.render_widget(hints, chunks[8]);
+}
+
+/// Editable RPC URL for the Custom network, with a hint while it isn't usable.
+fn draw_custom_url(f: &mut Frame, url: &str, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Length(1)])
+        .split(area);
+
+    let block = Block::default()
+        .title(" RPC URL ")
+        .borders(Borders::ALL)
+        .border_style(primary_style());
+    let input = Paragraph::new(url).block(block).style(text_style());
+    f.render_widget(input, chunks[0]);
+    f.set_cursor(
+        chunks[0].x + 1 + url.chars().count() as u16,
+        chunks[0].y + 1,
+    );
+
+    if !validators::is_valid_rpc_url(url) {
+        let hint = Paragraph::new(Span::styled(
+            "Enter an http:// or https:// URL",
+            error_style(),
+        ))
+        .alignment(Alignment::Center);
+        f.render_widget(hint, chunks[1]);
+    }
 }
 
 fn draw_network_button(f: &mut Frame, network: Network, selected: bool, area: Rect) {
-    let style = if selected { SELECTED_STYLE } else { DIM_STYLE };
+    let style = if selected {
+        selected_style()
+    } else {
+        dim_style()
+    };
 
     let block = Block::default()
         .title(format!(" {} ", network.name()))
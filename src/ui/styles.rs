@@ -1,50 +1,194 @@
 use ratatui::style::{Color, Modifier, Style};
+use std::cell::Cell;
 
-pub const PRIMARY_COLOR: Color = Color::Cyan;
-pub const SECONDARY_COLOR: Color = Color::Blue;
-pub const SUCCESS_COLOR: Color = Color::Green;
-pub const ERROR_COLOR: Color = Color::Red;
-#[allow(dead_code)]
-pub const WARNING_COLOR: Color = Color::Yellow;
-pub const TEXT_COLOR: Color = Color::White;
-pub const DIM_COLOR: Color = Color::Gray;
-pub const BG_COLOR: Color = Color::Black;
-
-pub const PRIMARY_STYLE: Style = Style::new().fg(PRIMARY_COLOR).bg(BG_COLOR);
-
-pub const SECONDARY_STYLE: Style = Style::new().fg(SECONDARY_COLOR).bg(BG_COLOR);
-
-pub const TEXT_STYLE: Style = Style::new().fg(TEXT_COLOR).bg(BG_COLOR);
-
-pub const DIM_STYLE: Style = Style::new().fg(DIM_COLOR).bg(BG_COLOR);
-
-pub const SUCCESS_STYLE: Style = Style::new()
-    .fg(SUCCESS_COLOR)
-    .bg(BG_COLOR)
-    .add_modifier(Modifier::BOLD);
-
-pub const ERROR_STYLE: Style = Style::new()
-    .fg(ERROR_COLOR)
-    .bg(BG_COLOR)
-    .add_modifier(Modifier::BOLD);
-
-#[allow(dead_code)]
-pub const WARNING_STYLE: Style = Style::new()
-    .fg(WARNING_COLOR)
-    .bg(BG_COLOR)
-    .add_modifier(Modifier::BOLD);
-
-pub const HEADER_STYLE: Style = Style::new()
-    .fg(PRIMARY_COLOR)
-    .bg(BG_COLOR)
-    .add_modifier(Modifier::BOLD);
-
-pub const SELECTED_STYLE: Style = Style::new()
-    .fg(BG_COLOR)
-    .bg(PRIMARY_COLOR)
-    .add_modifier(Modifier::BOLD);
-
-pub const HINT_STYLE: Style = Style::new()
-    .fg(DIM_COLOR)
-    .bg(BG_COLOR)
-    .add_modifier(Modifier::ITALIC);
+/// Colors behind every style the UI draws with. `ui::draw` installs the app's theme at the
+/// start of each frame, and the style functions below read from it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    pub primary: Color,
+    pub secondary: Color,
+    pub success: Color,
+    pub error: Color,
+    pub warning: Color,
+    /// Active tab and instruction names
+    pub accent: Color,
+    pub text: Color,
+    pub dim: Color,
+    pub bg: Color,
+}
+
+impl Theme {
+    pub const DARK: Theme = Theme {
+        name: "dark",
+        primary: Color::Cyan,
+        secondary: Color::Blue,
+        success: Color::Green,
+        error: Color::Red,
+        warning: Color::Yellow,
+        accent: Color::Yellow,
+        text: Color::White,
+        dim: Color::Gray,
+        bg: Color::Black,
+    };
+
+    /// For terminals with a light background.
+    pub const LIGHT: Theme = Theme {
+        name: "light",
+        primary: Color::Blue,
+        secondary: Color::Magenta,
+        success: Color::Green,
+        error: Color::Red,
+        warning: Color::Indexed(130),
+        accent: Color::Indexed(130),
+        text: Color::Black,
+        dim: Color::DarkGray,
+        bg: Color::White,
+    };
+
+    /// Bright, distinct colors on black, without relying on red/green alone.
+    pub const HIGH_CONTRAST: Theme = Theme {
+        name: "high-contrast",
+        primary: Color::LightYellow,
+        secondary: Color::White,
+        success: Color::LightCyan,
+        error: Color::LightMagenta,
+        warning: Color::LightYellow,
+        accent: Color::LightYellow,
+        text: Color::White,
+        dim: Color::White,
+        bg: Color::Black,
+    };
+
+    const ALL: [Theme; 3] = [Theme::DARK, Theme::LIGHT, Theme::HIGH_CONTRAST];
+
+    pub fn next(&self) -> Theme {
+        let index = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn from_name(name: &str) -> Option<Theme> {
+        Self::ALL
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+}
+
+thread_local! {
+    static ACTIVE: Cell<Theme> = const { Cell::new(Theme::DARK) };
+}
+
+pub fn set_theme(theme: Theme) {
+    ACTIVE.with(|active| active.set(theme));
+}
+
+fn theme() -> Theme {
+    ACTIVE.with(Cell::get)
+}
+
+pub fn primary_color() -> Color {
+    theme().primary
+}
+
+pub fn secondary_color() -> Color {
+    theme().secondary
+}
+
+pub fn success_color() -> Color {
+    theme().success
+}
+
+pub fn error_color() -> Color {
+    theme().error
+}
+
+pub fn warning_color() -> Color {
+    theme().warning
+}
+
+pub fn accent_color() -> Color {
+    theme().accent
+}
+
+pub fn text_color() -> Color {
+    theme().text
+}
+
+pub fn dim_color() -> Color {
+    theme().dim
+}
+
+pub fn bg_color() -> Color {
+    theme().bg
+}
+
+pub fn primary_style() -> Style {
+    Style::new().fg(primary_color()).bg(bg_color())
+}
+
+pub fn secondary_style() -> Style {
+    Style::new().fg(secondary_color()).bg(bg_color())
+}
+
+pub fn text_style() -> Style {
+    Style::new().fg(text_color()).bg(bg_color())
+}
+
+pub fn dim_style() -> Style {
+    Style::new().fg(dim_color()).bg(bg_color())
+}
+
+pub fn accent_style() -> Style {
+    Style::new().fg(accent_color()).bg(bg_color())
+}
+
+pub fn tab_style() -> Style {
+    text_style()
+}
+
+pub fn tab_active_style() -> Style {
+    accent_style().add_modifier(Modifier::BOLD)
+}
+
+pub fn success_style() -> Style {
+    Style::new()
+        .fg(success_color())
+        .bg(bg_color())
+        .add_modifier(Modifier::BOLD)
+}
+
+pub fn error_style() -> Style {
+    Style::new()
+        .fg(error_color())
+        .bg(bg_color())
+        .add_modifier(Modifier::BOLD)
+}
+
+pub fn warning_style() -> Style {
+    Style::new()
+        .fg(warning_color())
+        .bg(bg_color())
+        .add_modifier(Modifier::BOLD)
+}
+
+pub fn header_style() -> Style {
+    Style::new()
+        .fg(primary_color())
+        .bg(bg_color())
+        .add_modifier(Modifier::BOLD)
+}
+
+pub fn selected_style() -> Style {
+    Style::new()
+        .fg(bg_color())
+        .bg(primary_color())
+        .add_modifier(Modifier::BOLD)
+}
+
+pub fn hint_style() -> Style {
+    Style::new()
+        .fg(dim_color())
+        .bg(bg_color())
+        .add_modifier(Modifier::ITALIC)
+}
@@ -1,29 +1,45 @@
-use crate::app::{App, TransactionTab};
-use crate::solana::types::{TransactionData, TransactionStatus};
+use crate::app::{App, BalanceUnit, DataEncoding, TransactionTab};
+use crate::solana::summary;
+use crate::solana::types::{
+    get_program_name, AccountMeta, AccountSection, AccountSource, InstructionInfo,
+    LookupTableStatus, SystemInstruction, TransactionData, TransactionStatus,
+    LAMPORTS_PER_SIGNATURE,
+};
 use crate::ui::styles::*;
-use crate::ui::{format_sol, truncate_pubkey};
+use crate::ui::{
+    context_title, format_balance, format_balance_delta, highlight_line, relative_time,
+    scroll_markers, status_title, truncate_pubkey,
+};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
+    widgets::{block::Title, Block, Borders, Gauge, Paragraph, Tabs, Wrap},
     Frame,
 };
 
 pub fn draw(f: &mut Frame, app: &App) {
     let size = f.size();
-    let block = Block::default()
+    let mut block = Block::default()
         .title(" Transaction Details ")
+        .title(context_title(app))
         .borders(Borders::ALL)
-        .border_style(PRIMARY_STYLE);
+        .border_style(primary_style());
+    if let Some(status) = status_title(app) {
+        block = block.title(status);
+    }
     f.render_widget(block, size);
 
     if let Some(data) = &app.transaction_data {
-        draw_transaction_content(f, data, app, size);
+        if app.compact_view {
+            draw_compact(f, data, app.balance_unit, size);
+        } else {
+            draw_transaction_content(f, data, app, size);
+        }
     } else {
         let no_data = Paragraph::new("No transaction data available")
             .alignment(ratatui::layout::Alignment::Center)
-            .style(ERROR_STYLE);
+            .style(error_style());
         f.render_widget(no_data, size);
     }
 }
@@ -42,59 +58,141 @@ fn draw_transaction_content(f: &mut Frame, data: &TransactionData, app: &App, ar
         ])
         .split(inner);
 
-    draw_tabs(f, app, chunks[0]);
+    draw_tabs(f, data, app, chunks[0]);
+    // Only the tabs drawn through `render_lines` scroll sideways
+    app.h_scroll_max.set(0);
 
-    match app.transaction_tab {
-        TransactionTab::Overview => draw_overview(f, data, chunks[1]),
-        TransactionTab::Accounts => draw_accounts(f, data, app.txn_scroll, chunks[1]),
-        TransactionTab::Instructions => draw_instructions(f, data, app.txn_scroll, chunks[1]),
+    let max_scroll = match app.transaction_tab {
+        TransactionTab::Overview => {
+            draw_overview(f, data, app.balance_unit, chunks[1]);
+            0
+        }
+        TransactionTab::Fees => {
+            draw_fees(f, data, app.balance_unit, chunks[1]);
+            0
+        }
+        TransactionTab::Accounts => {
+            draw_accounts(f, data, app, chunks[1]);
+            0
+        }
+        TransactionTab::BalanceChanges => {
+            draw_balance_changes(f, data, app.balance_unit, app.txn_scroll, chunks[1])
+        }
+        TransactionTab::Instructions => draw_instructions(f, data, app, chunks[1]),
         TransactionTab::TokenTransfers => draw_token_transfers(f, data, app.txn_scroll, chunks[1]),
-        TransactionTab::Logs => draw_logs(f, data, app.txn_scroll, chunks[1]),
+        TransactionTab::Logs => draw_logs(f, data, app, chunks[1]),
+    };
+    app.txn_scroll_max.set(max_scroll);
+}
+
+/// How much each tab has to show, e.g. `Instructions: 5 (3 inner)  Accounts: 12  ...`.
+fn contents_line(data: &TransactionData) -> Line<'static> {
+    let inner: usize = data.inner_instructions.iter().map(Vec::len).sum();
+    let mut instructions = data.instructions.len().to_string();
+    if inner > 0 {
+        instructions.push_str(&format!(" ({} inner)", inner));
     }
+
+    Line::from(vec![
+        Span::styled("Instructions: ", header_style()),
+        Span::raw(instructions),
+        Span::styled("  Accounts: ", header_style()),
+        Span::raw(data.accounts.len().to_string()),
+        Span::styled("  Token Transfers: ", header_style()),
+        Span::raw(data.token_transfers.len().to_string()),
+        Span::styled("  SOL Transfers: ", header_style()),
+        Span::raw(data.sol_transfers.len().to_string()),
+    ])
 }
 
-fn draw_tabs(f: &mut Frame, app: &App, area: Rect) {
-    let titles = vec![
-        TransactionTab::Overview,
-        TransactionTab::Accounts,
-        TransactionTab::Instructions,
-        TransactionTab::TokenTransfers,
-        TransactionTab::Logs,
-    ]
-    .into_iter()
-    .map(|t| {
-        let title = t.title();
-        if t == app.transaction_tab {
-            Line::from(vec![Span::styled(
-                title,
-                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
-            )])
-        } else {
-            Line::from(vec![Span::styled(title, Style::default().fg(Color::Gray))])
-        }
-    })
-    .collect();
+fn priority_fee_line(data: &TransactionData, unit: BalanceUnit) -> Line<'static> {
+    let label = Span::styled("Priority Fee: ", header_style());
+    let (Some(price), Some(lamports)) = (data.priority_fee, data.priority_fee_lamports()) else {
+        return Line::from(vec![
+            label,
+            Span::styled("none (base fee only)", dim_style()),
+        ]);
+    };
+    let share = if data.fee > 0 {
+        lamports as f64 / data.fee as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    Line::from(vec![
+        label,
+        Span::raw(format_balance(lamports, unit)),
+        Span::styled(
+            format!(" ({} micro-lamports/CU, {:.1}% of fee)", price, share),
+            dim_style(),
+        ),
+    ])
+}
+
+fn draw_compact(f: &mut Frame, data: &TransactionData, unit: BalanceUnit, area: Rect) {
+    let inner = area.inner(&ratatui::layout::Margin {
+        horizontal: 1,
+        vertical: 1,
+    });
+
+    let style = match data.status {
+        TransactionStatus::Success => text_style(),
+        TransactionStatus::Failed(_) => error_style(),
+    };
+    let text = vec![
+        Line::from(Span::styled(summary::one_line_summary(data, unit), style)),
+        Line::from(""),
+        Line::from(Span::styled("Press 'm' for the full view", hint_style())),
+    ];
+
+    f.render_widget(Paragraph::new(text).wrap(Wrap { trim: true }), inner);
+}
+
+fn draw_tabs(f: &mut Frame, data: &TransactionData, app: &App, area: Rect) {
+    let titles = TransactionTab::ALL
+        .into_iter()
+        .map(|t| {
+            let title = t.title();
+            if t == app.transaction_tab {
+                Line::from(vec![Span::styled(title, tab_active_style())])
+            } else if t.is_empty_for(data) {
+                Line::from(vec![Span::styled(title, dim_style())])
+            } else {
+                Line::from(vec![Span::styled(title, tab_style())])
+            }
+        })
+        .collect();
 
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::BOTTOM))
         .select(app.transaction_tab as usize)
-        .highlight_style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .fg(Color::Yellow),
-        );
+        .highlight_style(tab_active_style());
 
     f.render_widget(tabs, area);
+    app.tab_areas.set(tab_areas(area));
+}
+
+/// Where `Tabs` draws each title: one space of padding either side, with a one-column
+/// divider between titles. Each area includes its padding so the whole cell is clickable.
+fn tab_areas(area: Rect) -> [Rect; TransactionTab::ALL.len()] {
+    let mut areas = [Rect::default(); TransactionTab::ALL.len()];
+    let mut x = area.x;
+    for (slot, tab) in areas.iter_mut().zip(TransactionTab::ALL) {
+        let width = (tab.title().len() as u16 + 2).min(area.right().saturating_sub(x));
+        *slot = Rect::new(x, area.y, width, 1);
+        x = x.saturating_add(width + 1);
+    }
+    areas
 }
 
-fn draw_overview(f: &mut Frame, data: &TransactionData, area: Rect) {
+fn draw_overview(f: &mut Frame, data: &TransactionData, unit: BalanceUnit, area: Rect) {
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(secondary_style());
 
     let status_style = match &data.status {
-        TransactionStatus::Success => SUCCESS_STYLE,
-        TransactionStatus::Failed(_) => ERROR_STYLE,
+        TransactionStatus::Success => success_style(),
+        TransactionStatus::Failed(_) => error_style(),
     };
 
     let status_text = match &data.status {
@@ -104,168 +202,858 @@ fn draw_overview(f: &mut Frame, data: &TransactionData, area: Rect) {
 
     let time_str = data
         .block_time
-        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .map(|t| {
+            format!(
+                "{} ({})",
+                t.format("%Y-%m-%d %H:%M:%S UTC"),
+                relative_time(t)
+            )
+        })
         .unwrap_or_else(|| "Unknown".to_string());
 
     let sig_str = data.signature.to_string();
 
-    let text = vec![
+    let mut text = vec![
+        Line::from(Span::styled(
+            data.summary.clone(),
+            text_style().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(vec![
+            Span::styled("Type: ", header_style()),
+            Span::styled(summary::classify(data), header_style()),
+        ]),
         Line::from(vec![
-            Span::styled("Signature: ", HEADER_STYLE),
+            Span::styled("Signature: ", header_style()),
             Span::raw(&sig_str),
         ]),
         Line::from(vec![
-            Span::styled("Slot: ", HEADER_STYLE),
+            Span::styled("Slot: ", header_style()),
             Span::raw(data.slot.to_string()),
+            Span::styled(format!(" ({})", data.commitment.name()), dim_style()),
         ]),
         Line::from(vec![
-            Span::styled("Time: ", HEADER_STYLE),
+            Span::styled("Time: ", header_style()),
             Span::raw(time_str),
         ]),
+        version_line(data),
         Line::from(vec![
-            Span::styled("Status: ", HEADER_STYLE),
+            Span::styled("Status: ", header_style()),
             Span::styled(status_text.to_string(), status_style),
         ]),
+    ];
+
+    if let Some(reason) = &data.failure_reason {
+        text.push(Line::from(vec![
+            Span::styled("Reason: ", header_style()),
+            Span::styled(reason.clone(), error_style()),
+        ]));
+    }
+
+    text.push(Line::from(vec![
+        Span::styled("Fee: ", header_style()),
+        Span::raw(format_balance(data.fee, unit)),
+    ]));
+
+    text.extend(fee_payer_lines(data, unit));
+    text.push(Line::from(vec![
+        Span::styled("SOL Moved: ", header_style()),
+        Span::raw(format_balance(data.sol_moved(), unit)),
+    ]));
+    text.extend(signature_lines(data));
+    text.push(contents_line(data));
+
+    if let TransactionStatus::Failed(_) = &data.status {
+        text.push(Line::from(Span::styled(
+            format!(
+                "Fee charged despite failure: {}",
+                format_balance(data.fee, unit)
+            ),
+            warning_style(),
+        )));
+
+        if data.exceeded_compute_limit() {
+            let hint = match data.suggested_compute_limit() {
+                Some(limit) => format!(
+                    "Ran out of compute units; retry with a compute unit limit of ~{}",
+                    limit
+                ),
+                None => {
+                    "Ran out of compute units; retry with a higher compute unit limit".to_string()
+                }
+            };
+            text.push(Line::from(Span::styled(hint, warning_style())));
+        }
+    }
+
+    text.extend([Line::from(""), priority_fee_line(data, unit)]);
+
+    if let Some(limit) = data.loaded_accounts_data_size_limit {
+        text.push(Line::from(vec![
+            Span::styled("Loaded Accounts Data Limit: ", header_style()),
+            Span::raw(format!("{} bytes", limit)),
+        ]));
+    }
+    if data.exceeded_loaded_accounts_data_size() {
+        let limit = data.loaded_accounts_data_size_limit.map_or_else(
+            || "the default limit".to_string(),
+            |l| format!("{} bytes", l),
+        );
+        text.push(Line::from(Span::styled(
+            format!(
+                "Loaded account data exceeded {}; raise it with SetLoadedAccountsDataSizeLimit",
+                limit
+            ),
+            warning_style(),
+        )));
+    }
+
+    if !data.lookup_tables.is_empty() {
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled("Lookup Tables:", header_style())));
+    }
+    for table in &data.lookup_tables {
+        let (status, style) = match table.status {
+            LookupTableStatus::Active => ("active".to_string(), success_style()),
+            LookupTableStatus::Deactivated(slot) => {
+                (format!("deactivated at slot {}", slot), warning_style())
+            }
+            LookupTableStatus::NotFound => ("not found (closed)".to_string(), error_style()),
+            LookupTableStatus::Unavailable => ("could not be fetched".to_string(), dim_style()),
+        };
+        text.push(Line::from(vec![
+            Span::raw(format!(
+                "  {}: ",
+                truncate_pubkey(&table.address.to_string())
+            )),
+            Span::styled(status, style),
+        ]));
+    }
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+
+    draw_compute_gauge(f, data, chunks[0]);
+    let paragraph = Paragraph::new(text)
+        .style(text_style())
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, chunks[1]);
+}
+
+/// Compute units consumed against the limit, turning yellow past half and red near the limit.
+fn draw_compute_gauge(f: &mut Frame, data: &TransactionData, area: Rect) {
+    let consumed = data.compute_units_consumed.unwrap_or(0);
+    let limit = data.compute_unit_limit();
+    let ratio = if limit > 0 {
+        (consumed as f64 / limit as f64).min(1.0)
+    } else {
+        0.0
+    };
+    let color = match ratio {
+        r if r < 0.5 => success_color(),
+        r if r < 0.9 => warning_color(),
+        _ => error_color(),
+    };
+    let implied = if data.max_compute_units.is_none() {
+        " implied"
+    } else {
+        ""
+    };
+
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(color).bg(bg_color()))
+        .ratio(ratio)
+        .label(format!(
+            "Compute Units: {} / {}{} ({:.0}%)",
+            consumed,
+            limit,
+            implied,
+            ratio * 100.0
+        ))
+        .use_unicode(true);
+    f.render_widget(gauge, area);
+}
+
+/// How the fee was composed: the per-signature base fee plus the priority fee, compared
+/// with what was actually charged.
+fn draw_fees(f: &mut Frame, data: &TransactionData, unit: BalanceUnit, area: Rect) {
+    let block = Block::default()
+        .title(" Fees ")
+        .borders(Borders::ALL)
+        .border_style(secondary_style());
+
+    let signatures = data.signatures.len().max(1);
+    let base_fee = data.base_fee();
+    let priority_fee = data.priority_fee_lamports().unwrap_or(0);
+    let limit = data.compute_unit_limit();
+    let limit_note = if data.max_compute_units.is_some() {
+        "requested"
+    } else {
+        "implied by the instruction count"
+    };
+
+    let mut text = vec![
         Line::from(vec![
-            Span::styled("Fee: ", HEADER_STYLE),
-            Span::raw(format_sol(data.fee)),
+            Span::styled("Base Fee: ", header_style()),
+            Span::raw(format_balance(base_fee, unit)),
+            Span::styled(
+                format!(
+                    " ({} signature{} × {} lamports)",
+                    signatures,
+                    if signatures == 1 { "" } else { "s" },
+                    LAMPORTS_PER_SIGNATURE
+                ),
+                dim_style(),
+            ),
         ]),
-        Line::from(""),
         Line::from(vec![
-            Span::styled("Compute Units: ", HEADER_STYLE),
-            Span::raw(format!(
-                "{} / {}",
-                data.compute_units_consumed.unwrap_or(0),
-                data.max_compute_units.unwrap_or(200_000)
-            )),
+            Span::styled("Priority Fee: ", header_style()),
+            Span::raw(format_balance(priority_fee, unit)),
+            Span::styled(
+                format!(
+                    " ({} micro-lamports/CU × {} CU {})",
+                    data.priority_fee.unwrap_or(0),
+                    limit,
+                    limit_note
+                ),
+                dim_style(),
+            ),
         ]),
         Line::from(vec![
-            Span::styled("Priority Fee: ", HEADER_STYLE),
-            Span::raw(format!(
-                "{} micro-lamports",
-                data.priority_fee.unwrap_or(0)
-            )),
+            Span::styled("Total: ", header_style()),
+            Span::raw(format_balance(base_fee + priority_fee, unit)),
+        ]),
+        Line::from(vec![
+            Span::styled("Charged: ", header_style()),
+            Span::raw(format_balance(data.fee, unit)),
         ]),
     ];
 
+    if base_fee + priority_fee != data.fee {
+        text.push(Line::from(Span::styled(
+            "Charged fee differs from the estimate (e.g. precompile signatures also pay the \
+             base fee)",
+            warning_style(),
+        )));
+    }
+
+    if let Some(consumed) = data.compute_units_consumed.filter(|&cu| cu > 0) {
+        text.extend([
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Compute Units: ", header_style()),
+                Span::raw(format!("{} consumed of {}", consumed, limit)),
+            ]),
+            Line::from(vec![
+                Span::styled("Effective Price: ", header_style()),
+                Span::raw(format!(
+                    "{:.2} lamports per CU consumed",
+                    data.fee as f64 / consumed as f64
+                )),
+            ]),
+        ]);
+    }
+
+    text.extend([
+        Line::from(""),
+        Line::from(Span::styled(
+            "The priority fee is paid on the requested compute unit limit, not the units \
+             consumed. Half the base fee is burned and the rest, with the whole priority \
+             fee, goes to the block's leader.",
+            dim_style(),
+        )),
+    ]);
+
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(TEXT_STYLE)
+        .style(text_style())
         .wrap(Wrap { trim: true });
-
     f.render_widget(paragraph, area);
 }
 
-fn draw_accounts(f: &mut Frame, data: &TransactionData, scroll: usize, area: Rect) {
+fn draw_accounts(f: &mut Frame, data: &TransactionData, app: &App, area: Rect) {
+    let title = if let Some(input) = &app.balance_filter_input {
+        format!(
+            " Accounts | min |Δ| in SOL: {}_ (Enter to apply, Esc to cancel) ",
+            input
+        )
+    } else if let Some(threshold) = app.balance_filter {
+        let shown = data
+            .accounts
+            .iter()
+            .filter(|a| app.passes_balance_filter(a))
+            .count();
+        format!(
+            " Accounts ({} of {}, |Δ| ≥ {}) ",
+            shown,
+            data.accounts.len(),
+            format_balance(threshold, app.balance_unit)
+        )
+    } else {
+        format!(" Accounts ({}) ", data.accounts.len())
+    };
     let block = Block::default()
-        .title(format!(" Accounts ({}) ", data.accounts.len()))
+        .title(title)
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(secondary_style());
 
     let mut text: Vec<Line> = Vec::new();
+    let selected = app.accounts_selected;
+    let mut selected_end = 0;
+
+    if let Some(warning) = &data.balance_warning {
+        text.push(Line::from(Span::styled(
+            format!("⚠ {}", warning),
+            warning_style(),
+        )));
+    }
+
+    let mut section = None;
+    for i in app.listed_accounts() {
+        let acc = &data.accounts[i];
+        let acc_section = AccountSection::of(acc);
+        if section != Some(acc_section) {
+            section = Some(acc_section);
+            text.push(Line::from(Span::styled(
+                acc_section.title(),
+                header_style(),
+            )));
+        }
 
-    for (i, acc) in data.accounts.iter().enumerate() {
         let balance_change = if let (Some(pre), Some(post)) = (acc.pre_balance, acc.post_balance) {
             let change = post as i64 - pre as i64;
-            if change > 0 {
-                format!(" (+{:.9} SOL)", change as f64 / 1_000_000_000.0)
-            } else if change < 0 {
-                format!(" ({:.9} SOL)", change as f64 / 1_000_000_000.0)
+            if change != 0 {
+                format!(" ({})", format_balance_delta(change, app.balance_unit))
             } else {
                 " (no change)".to_string()
             }
+        } else if data.balance_warning.is_some() {
+            " (balance unknown)".to_string()
         } else {
             String::new()
         };
 
         let flags = format!(
-            "{}{}",
+            "{}{}{}",
             if acc.is_signer { "S" } else { " " },
-            if acc.is_writable { "W" } else { " " }
+            if acc.is_writable { "W" } else { " " },
+            if acc.source == AccountSource::Lookup {
+                "L"
+            } else {
+                " "
+            }
         );
 
         let style = if balance_change.starts_with(" (+") {
-            SUCCESS_STYLE
-        } else if balance_change.starts_with(" (") && !balance_change.contains("no change") {
-            ERROR_STYLE
+            success_style()
+        } else if balance_change.starts_with(" (-") {
+            error_style()
         } else {
-            DIM_STYLE
+            dim_style()
         };
 
-        text.push(Line::from(vec![
-            Span::styled(format!("{:<3} ", i), DIM_STYLE),
+        let line = Line::from(vec![
+            Span::styled(format!("{:<3} ", i), dim_style()),
             Span::raw(flags),
             Span::raw(" "),
             Span::raw(truncate_pubkey(&acc.pubkey.to_string())),
             Span::styled(balance_change, style),
-        ]));
+        ]);
+
+        text.push(if i == selected {
+            highlight_line(line)
+        } else {
+            line
+        });
+
+        if i == 0 {
+            text.extend(fee_payer_breakdown(data, acc, app.balance_unit));
+        }
+
+        if i == selected {
+            if app.accounts_expanded {
+                text.extend(account_references(data, acc));
+            }
+            selected_end = text.len();
+        }
     }
 
-    // Scroll handling
-    let visible_lines = area.height as usize - 2;
-    let _total_lines = text.len();
-    
+    // Scroll so the selected row (and its expansion) stays in view
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let scroll = selected_end.saturating_sub(visible_lines);
+    let block = block.title(scroll_markers(scroll, text.len(), visible_lines));
+
     let display_text: Vec<Line> = text.into_iter().skip(scroll).take(visible_lines).collect();
 
     let paragraph = Paragraph::new(display_text)
         .block(block)
-        .style(TEXT_STYLE)
+        .style(text_style())
         .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
 }
 
-fn draw_instructions(f: &mut Frame, data: &TransactionData, scroll: usize, area: Rect) {
+/// Who paid the fee, and their balance change split into the fee and everything else.
+fn fee_payer_lines(data: &TransactionData, unit: BalanceUnit) -> Vec<Line<'static>> {
+    let Some(payer) = data.fee_payer() else {
+        return Vec::new();
+    };
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Fee Payer: ", header_style()),
+        Span::raw(payer.pubkey.to_string()),
+    ])];
+    if let Some(delta) = data.fee_payer_delta() {
+        lines.push(Line::from(vec![
+            Span::styled("  Net Change: ", header_style()),
+            Span::raw(format_balance_delta(delta, unit)),
+            Span::styled(
+                format!(
+                    " ({} excluding the fee)",
+                    format_balance_delta(delta + data.fee as i64, unit)
+                ),
+                dim_style(),
+            ),
+        ]));
+    }
+    lines
+}
+
+/// Every signature with the signer it belongs to, for transactions signed by more than
+/// one account.
+fn signature_lines(data: &TransactionData) -> Vec<Line<'static>> {
+    if data.signatures.len() < 2 {
+        return Vec::new();
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        format!("Signatures ({}):", data.signatures.len()),
+        header_style(),
+    ))];
+    for (i, signature) in data.signatures.iter().enumerate() {
+        let signer = data.accounts.get(i).map_or_else(
+            || "unknown signer".to_string(),
+            |acc| truncate_pubkey(&acc.pubkey.to_string()),
+        );
+        let mut spans = vec![
+            Span::raw(format!(
+                "  {}. {} ",
+                i + 1,
+                truncate_pubkey(&signature.to_string())
+            )),
+            Span::styled(format!("by {}", signer), dim_style()),
+        ];
+        if i == 0 {
+            spans.push(Span::styled(" (fee payer)", dim_style()));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines
+}
+
+/// Transaction version, with how many accounts a v0 transaction pulled from lookup tables.
+fn version_line(data: &TransactionData) -> Line<'static> {
+    let mut spans = vec![
+        Span::styled("Version: ", header_style()),
+        Span::raw(data.version.clone().unwrap_or_else(|| "legacy".to_string())),
+    ];
+    if !data.loaded_addresses.is_empty() {
+        spans.push(Span::styled(
+            format!(
+                " ({} accounts from {} lookup tables)",
+                data.loaded_addresses.len(),
+                data.lookup_tables.len()
+            ),
+            dim_style(),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Every account whose SOL balance changed, largest change first, with the net total.
+/// Returns the largest scroll offset that still fills the pane.
+fn draw_balance_changes(
+    f: &mut Frame,
+    data: &TransactionData,
+    unit: BalanceUnit,
+    scroll: usize,
+    area: Rect,
+) -> usize {
+    let mut changes: Vec<(usize, &AccountMeta, i64)> = data
+        .accounts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, acc)| {
+            let delta = acc.post_balance? as i64 - acc.pre_balance? as i64;
+            (delta != 0).then_some((i, acc, delta))
+        })
+        .collect();
+    changes.sort_by_key(|&(_, _, delta)| std::cmp::Reverse(delta.unsigned_abs()));
+    let net: i64 = changes.iter().map(|&(_, _, delta)| delta).sum();
+
+    let block = Block::default()
+        .title(format!(" Balance Changes ({}) ", changes.len()))
+        .borders(Borders::ALL)
+        .border_style(secondary_style());
+
+    let mut text: Vec<Line> = Vec::new();
+    if let Some(warning) = &data.balance_warning {
+        text.push(Line::from(Span::styled(
+            format!("⚠ {}", warning),
+            warning_style(),
+        )));
+    }
+    if changes.is_empty() {
+        text.push(Line::from(Span::styled(
+            "No SOL balances changed",
+            dim_style(),
+        )));
+    }
+    for &(i, acc, delta) in &changes {
+        let style = if delta > 0 {
+            success_style()
+        } else {
+            error_style()
+        };
+        let mut spans = vec![
+            Span::styled(format!("{:<3} ", i), dim_style()),
+            Span::styled(format!("{:>24} ", format_balance_delta(delta, unit)), style),
+            Span::raw(truncate_pubkey(&acc.pubkey.to_string())),
+        ];
+        if let Some(name) = get_program_name(&acc.pubkey) {
+            spans.push(Span::styled(format!(" ({})", name), dim_style()));
+        } else if i == 0 {
+            spans.push(Span::styled(" (fee payer)", dim_style()));
+        }
+        text.push(Line::from(spans));
+    }
+    if !changes.is_empty() {
+        text.extend([
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Net: ", header_style()),
+                Span::raw(format_balance_delta(net, unit)),
+                Span::styled(" (normally just the fee)", dim_style()),
+            ]),
+        ]);
+    }
+
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let max_scroll = text.len().saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
+    let block = block.title(scroll_markers(scroll, text.len(), visible_lines));
+    let text: Vec<Line> = text.into_iter().skip(scroll).take(visible_lines).collect();
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .style(text_style())
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+    max_scroll
+}
+
+/// Splits the fee payer's balance delta into the amount moved and the fee, since the raw
+/// delta alone never matches what was actually sent.
+fn fee_payer_breakdown(
+    data: &TransactionData,
+    fee_payer: &AccountMeta,
+    unit: BalanceUnit,
+) -> Option<Line<'static>> {
+    let (pre, post) = (fee_payer.pre_balance?, fee_payer.post_balance?);
+    let delta = post as i64 - pre as i64;
+    let moved = delta + data.fee as i64;
+
+    let movement = if moved < 0 {
+        format!("sent {} + ", format_balance(moved.unsigned_abs(), unit))
+    } else if moved > 0 {
+        format!("received {} - ", format_balance(moved.unsigned_abs(), unit))
+    } else {
+        String::new()
+    };
+
+    Some(Line::from(Span::styled(
+        format!(
+            "      fee payer: {}fee {} = {}",
+            movement,
+            format_balance(data.fee, unit),
+            format_balance_delta(delta, unit)
+        ),
+        dim_style(),
+    )))
+}
+
+/// Lines listing every instruction that references `account`, and in what role.
+fn account_references<'a>(data: &'a TransactionData, account: &AccountMeta) -> Vec<Line<'a>> {
+    let mut lines = Vec::new();
+
+    for (i, ix) in data.instructions.iter().enumerate() {
+        for ix_acc in ix.accounts.iter().filter(|a| a.pubkey == account.pubkey) {
+            let mut roles = Vec::new();
+            if let Some(name) = &ix_acc.account_type {
+                roles.push(name.as_str());
+            }
+            if account.is_signer {
+                roles.push("signer");
+            }
+            roles.push(if account.is_writable {
+                "writable"
+            } else {
+                "readonly"
+            });
+
+            lines.push(Line::from(vec![
+                Span::styled(format!("      ↳ #{} ", i + 1), dim_style()),
+                Span::styled(
+                    ix.program_name.as_deref().unwrap_or("Unknown Program"),
+                    primary_style(),
+                ),
+                Span::raw(" > "),
+                Span::styled(&ix.instruction_type, accent_style()),
+                Span::styled(format!(" [{}]", roles.join(", ")), dim_style()),
+            ]));
+        }
+    }
+
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "      ↳ Not referenced by any instruction",
+            dim_style(),
+        )));
+    }
+
+    lines
+}
+
+/// Returns the largest scroll offset that still fills the pane.
+fn draw_instructions(f: &mut Frame, data: &TransactionData, app: &App, area: Rect) -> usize {
+    let (encoding, unit, scroll) = (app.data_encoding, app.balance_unit, app.txn_scroll);
     let block = Block::default()
-        .title(format!(" Instructions ({}) ", data.instructions.len()))
+        .title(format!(
+            " Instructions ({}) · data: {} (d) ",
+            data.instructions.len(),
+            encoding.name()
+        ))
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(secondary_style());
 
     let mut text: Vec<Line> = Vec::new();
 
     for (i, ix) in data.instructions.iter().enumerate() {
-        let program_name = ix.program_name.as_deref().unwrap_or("Unknown Program");
-        
-        text.push(Line::from(vec![
-            Span::styled(format!("#{}: ", i + 1), HEADER_STYLE),
-            Span::styled(program_name, Style::default().fg(Color::Cyan)),
+        text.extend(instruction_lines(
+            data,
+            ix,
+            encoding,
+            unit,
+            format!("#{}", i + 1),
+            "",
+        ));
+
+        // CPI calls made by this instruction, nested under it
+        let inner = data
+            .inner_instructions
+            .get(i)
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        if !inner.is_empty() {
+            text.push(Line::from(Span::styled(
+                format!("    Inner instructions ({}):", inner.len()),
+                dim_style(),
+            )));
+        }
+        for (j, inner_ix) in inner.iter().enumerate() {
+            let label = format!("#{}.{}", i + 1, j + 1);
+            text.extend(instruction_lines(
+                data, inner_ix, encoding, unit, label, "    ",
+            ));
+        }
+
+        text.push(Line::from("")); // Separator
+    }
+
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let max_scroll = text.len().saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
+    let block = block.title(scroll_markers(scroll, text.len(), visible_lines));
+    let display_text: Vec<Line> = text.into_iter().skip(scroll).take(visible_lines).collect();
+
+    render_lines(f, display_text, block, app, area);
+    max_scroll
+}
+
+/// Header, program, data and account lines for one instruction, shifted right by `indent`.
+fn instruction_lines<'a>(
+    data: &TransactionData,
+    ix: &'a InstructionInfo,
+    encoding: DataEncoding,
+    unit: BalanceUnit,
+    label: String,
+    indent: &str,
+) -> Vec<Line<'a>> {
+    let program_name = ix.program_name.as_deref().unwrap_or("Unknown Program");
+    // Compute Budget instructions carry a single value, shown next to the name
+    let instruction_type = match ix.compute_budget() {
+        Some(decoded) => Span::styled(decoded.to_string(), accent_style()),
+        None => Span::styled(&ix.instruction_type, accent_style()),
+    };
+
+    let mut text = vec![
+        Line::from(vec![
+            Span::raw(indent.to_string()),
+            Span::styled(format!("{}: ", label), header_style()),
+            Span::styled(program_name, primary_style()),
             Span::raw(" > "),
-            Span::styled(&ix.instruction_type, Style::default().fg(Color::Yellow)),
-        ]));
+            instruction_type,
+        ]),
+        Line::from(format!(
+            "{}    Program ID: {}",
+            indent,
+            truncate_pubkey(&ix.program_id.to_string())
+        )),
+    ];
 
+    if let Some(decoded) = ix.system_instruction() {
+        text.push(system_instruction_line(ix, decoded, unit, indent));
+    }
+    if let Some(memo) = ix.memo_text() {
         text.push(Line::from(vec![
-             Span::raw("    Program ID: "),
-             Span::raw(truncate_pubkey(&ix.program_id.to_string())),
+            Span::raw(format!("{}    ", indent)),
+            Span::styled("Memo: ", header_style()),
+            Span::styled(memo, success_style()),
         ]));
+    }
+    text.extend(data_lines(ix, encoding, indent));
 
+    if let (Some(discriminator), Some(raw)) = (ix.discriminator(), &ix.raw_data) {
         text.push(Line::from(vec![
-            Span::raw("    Data: "),
-            Span::raw(if ix.data.len() > 50 { 
-                format!("{}...", &ix.data[..50]) 
-            } else { 
-                ix.data.clone() 
-            }),
+            Span::raw(format!("{}    Discriminator: ", indent)),
+            Span::styled(format!("0x{}", discriminator), secondary_style()),
+            Span::styled(format!(" ({} bytes)", raw.len()), dim_style()),
         ]));
-        
-        text.push(Line::from("")); // Separator
     }
 
-    let visible_lines = area.height as usize - 2;
-    let display_text: Vec<Line> = text.into_iter().skip(scroll).take(visible_lines).collect();
+    if !ix.accounts.is_empty() {
+        text.push(Line::from(format!("{}    Accounts:", indent)));
+    }
+    for acc in &ix.accounts {
+        // Index into the transaction's account list, to line up with the Accounts tab
+        let index = data
+            .accounts
+            .iter()
+            .position(|a| a.pubkey == acc.pubkey)
+            .map(|i| format!("#{:<3}", i))
+            .unwrap_or_else(|| "#?  ".to_string());
 
-    let paragraph = Paragraph::new(display_text)
-        .block(block)
-        .style(TEXT_STYLE)
-        .wrap(Wrap { trim: false }); // False to avoid wrapping code/data weirdly
+        let mut spans = vec![
+            Span::raw(format!("{}      ", indent)),
+            Span::styled(index, dim_style()),
+            Span::raw(" "),
+            Span::raw(truncate_pubkey(&acc.pubkey.to_string())),
+        ];
+        if let Some(role) = &acc.account_type {
+            spans.push(Span::styled(format!(" ({})", role), dim_style()));
+        }
+        text.push(Line::from(spans));
+    }
 
-    f.render_widget(paragraph, area);
+    text
+}
+
+/// Plain-language reading of a System instruction the RPC didn't parse.
+fn system_instruction_line(
+    ix: &InstructionInfo,
+    decoded: SystemInstruction,
+    unit: BalanceUnit,
+    indent: &str,
+) -> Line<'static> {
+    let account = |i: usize| {
+        ix.accounts.get(i).map_or_else(
+            || "?".to_string(),
+            |a| truncate_pubkey(&a.pubkey.to_string()),
+        )
+    };
+    let text = match decoded {
+        SystemInstruction::Transfer { lamports } => format!(
+            "Transfer: {} from {} to {}",
+            format_balance(lamports, unit),
+            account(0),
+            account(1)
+        ),
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => format!(
+            "CreateAccount: {} with {} bytes owned by {}, funded with {} by {}",
+            account(1),
+            space,
+            get_program_name(&owner)
+                .map(str::to_string)
+                .unwrap_or_else(|| truncate_pubkey(&owner.to_string())),
+            format_balance(lamports, unit),
+            account(0)
+        ),
+        SystemInstruction::Allocate { space } => {
+            format!("Allocate: {} bytes for {}", space, account(0))
+        }
+    };
+    Line::from(vec![
+        Span::raw(format!("{}    ", indent)),
+        Span::styled(text, success_style()),
+    ])
+}
+
+/// Characters of instruction data shown per line.
+const DATA_LINE_WIDTH: usize = 64;
+/// Lines of instruction data shown before the rest is elided.
+const MAX_DATA_LINES: usize = 8;
+
+/// Instruction data in `encoding`, split over fixed-width lines so scrolling stays exact.
+/// Parsed instructions have no raw bytes and keep their JSON.
+fn data_lines(ix: &InstructionInfo, encoding: DataEncoding, indent: &str) -> Vec<Line<'static>> {
+    let (encoded, byte_count) = match &ix.raw_data {
+        Some(raw) => (encoding.encode(raw), Some(raw.len())),
+        None => (ix.data.clone(), None),
+    };
+    let chars: Vec<char> = encoded.chars().collect();
+    let chunks: Vec<String> = chars
+        .chunks(DATA_LINE_WIDTH)
+        .map(|chunk| chunk.iter().collect())
+        .collect();
+
+    let mut lines = Vec::new();
+    for (i, chunk) in chunks.iter().take(MAX_DATA_LINES).enumerate() {
+        let prefix = if i == 0 { "Data: " } else { "      " };
+        let mut spans = vec![Span::raw(format!("{}    {}{}", indent, prefix, chunk))];
+        if i == 0 {
+            if let Some(count) = byte_count {
+                spans.push(Span::styled(format!(" ({} bytes)", count), dim_style()));
+            }
+        }
+        lines.push(Line::from(spans));
+    }
+    if chunks.is_empty() {
+        lines.push(Line::from(format!("{}    Data: (empty)", indent)));
+    }
+    if chunks.len() > MAX_DATA_LINES {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{}          ... {} more characters",
+                indent,
+                chars.len() - MAX_DATA_LINES * DATA_LINE_WIDTH
+            ),
+            dim_style(),
+        )));
+    }
+    lines
 }
 
-fn draw_token_transfers(f: &mut Frame, data: &TransactionData, scroll: usize, area: Rect) {
+/// Returns the largest scroll offset that still fills the pane.
+fn draw_token_transfers(f: &mut Frame, data: &TransactionData, scroll: usize, area: Rect) -> usize {
     let block = Block::default()
         .title(format!(" Token Transfers ({}) ", data.token_transfers.len()))
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(secondary_style());
 
     let mut text: Vec<Line> = Vec::new();
 
@@ -276,10 +1064,11 @@ fn draw_token_transfers(f: &mut Frame, data: &TransactionData, scroll: usize, ar
             let amount = transfer.amount as f64 / 10f64.powi(transfer.decimals as i32);
             
             text.push(Line::from(vec![
-                Span::styled(format!("{}. ", i + 1), DIM_STYLE),
-                Span::styled(format!("{:.4}", amount), SUCCESS_STYLE),
+                Span::styled(format!("{}. ", i + 1), dim_style()),
+                Span::styled(format!("{:.4}", amount), success_style()),
                 Span::raw(" "),
                 Span::raw(transfer.token_name.as_deref().unwrap_or("Token")),
+                Span::styled(format!("  [{}]", transfer.source.label()), dim_style()),
             ]));
 
             text.push(Line::from(vec![
@@ -291,44 +1080,120 @@ fn draw_token_transfers(f: &mut Frame, data: &TransactionData, scroll: usize, ar
                 Span::raw("   To:   "),
                 Span::raw(truncate_pubkey(&transfer.to.to_string())),
             ]));
+
+            if let Some(fee) = transfer.fee {
+                let scale = 10f64.powi(transfer.decimals as i32);
+                let received = transfer.amount.saturating_sub(fee) as f64 / scale;
+                text.push(Line::from(vec![
+                    Span::styled("   Token-2022 transfer fee: ", warning_style()),
+                    Span::raw(format!(
+                        "{:.4} withheld, {:.4} received",
+                        fee as f64 / scale,
+                        received
+                    )),
+                ]));
+            }
             
             text.push(Line::from(""));
         }
     }
 
-    let visible_lines = area.height as usize - 2;
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let max_scroll = text.len().saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
+    let block = block.title(scroll_markers(scroll, text.len(), visible_lines));
     let display_text: Vec<Line> = text.into_iter().skip(scroll).take(visible_lines).collect();
 
     let paragraph = Paragraph::new(display_text)
         .block(block)
-        .style(TEXT_STYLE)
+        .style(text_style())
         .wrap(Wrap { trim: true });
 
     f.render_widget(paragraph, area);
+    max_scroll
 }
 
-fn draw_logs(f: &mut Frame, data: &TransactionData, scroll: usize, area: Rect) {
+/// Returns the largest scroll offset that still fills the pane.
+fn draw_logs(f: &mut Frame, data: &TransactionData, app: &App, area: Rect) -> usize {
+    let scroll = app.txn_scroll;
     let block = Block::default()
         .title(format!(" Logs ({} lines) ", data.logs.len()))
         .borders(Borders::ALL)
-        .border_style(SECONDARY_STYLE);
+        .border_style(secondary_style());
 
-    let mut text: Vec<Line> = data
+    let visible_lines = area.height.saturating_sub(2) as usize;
+    let max_scroll = data.logs.len().saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
+    let block = block.title(scroll_markers(scroll, data.logs.len(), visible_lines));
+
+    // Depth depends on every earlier line, so walk the whole log before skipping
+    let mut depth = 0;
+    let text: Vec<Line> = data
         .logs
         .iter()
+        .map(|log| log_line(log, &mut depth))
         .skip(scroll)
-        .take(area.height as usize - 2)
-        .map(|log| Line::from(log.as_str()))
+        .take(visible_lines)
         .collect();
 
-    if text.is_empty() && !data.logs.is_empty() {
-        text.push(Line::from("Scroll up to see logs"));
-    }
+    render_lines(f, text, block, app, area);
+    max_scroll
+}
 
-    let paragraph = Paragraph::new(text)
-        .block(block)
-        .style(TEXT_STYLE)
-        .wrap(Wrap { trim: false });
+/// Renders already-scrolled lines, wrapped while `app.h_scroll` is zero and otherwise cut at
+/// the pane edge and shifted left by that many columns. Records how far the widest line can
+/// be shifted in `app.h_scroll_max`.
+fn render_lines(f: &mut Frame, lines: Vec<Line>, block: Block, app: &App, area: Rect) {
+    let width = area.width.saturating_sub(2) as usize;
+    let widest = lines.iter().map(Line::width).max().unwrap_or(0);
+    let max_h_scroll = widest.saturating_sub(width);
+    app.h_scroll_max.set(max_h_scroll);
+    let h_scroll = app.h_scroll.min(max_h_scroll);
 
+    let paragraph = Paragraph::new(lines).style(text_style());
+    let paragraph = if h_scroll == 0 {
+        // Trimming would eat the indentation of nested instructions and log lines
+        paragraph.block(block).wrap(Wrap { trim: false })
+    } else {
+        let marker = Span::styled(format!(" ◀ col {} ", h_scroll + 1), hint_style());
+        paragraph
+            .block(block.title(Title::from(marker).alignment(Alignment::Right)))
+            .scroll((0, h_scroll as u16))
+    };
     f.render_widget(paragraph, area);
+}
+
+/// One log line, indented by CPI depth and colored by kind. Invocation markers sit at the
+/// caller's depth, and the lines a program emits one level further in. `depth` tracks the
+/// current invocation depth across calls.
+fn log_line<'a>(log: &'a str, depth: &mut usize) -> Line<'a> {
+    let (indent, style) = if let Some(level) = invoke_depth(log) {
+        *depth = level;
+        (level.saturating_sub(1), primary_style())
+    } else if log.starts_with("Program ") && log.ends_with(" success") {
+        let indent = depth.saturating_sub(1);
+        *depth = indent;
+        (indent, success_style())
+    } else if log.starts_with("Program ") && log.contains(" failed") {
+        let indent = depth.saturating_sub(1);
+        *depth = indent;
+        (indent, error_style())
+    } else if log.starts_with("Program ") && log.contains(" consumed ") {
+        (depth.saturating_sub(1), dim_style())
+    } else if log.contains("Error") || log.contains("failed") {
+        (*depth, error_style())
+    } else {
+        (*depth, text_style())
+    };
+
+    Line::from(vec![
+        Span::raw("  ".repeat(indent)),
+        Span::styled(log, style),
+    ])
+}
+
+/// `N` from a "Program <id> invoke [N]" line.
+fn invoke_depth(log: &str) -> Option<usize> {
+    let (_, level) = log.strip_prefix("Program ")?.split_once(" invoke [")?;
+    level.strip_suffix(']')?.parse().ok()
 }
\ No newline at end of file
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use arboard::Clipboard;
+use std::cell::RefCell;
+
+thread_local! {
+    // Kept alive for the whole session: on X11 the copied text disappears once the
+    // clipboard handle that owns it is dropped.
+    static CLIPBOARD: RefCell<Option<Clipboard>> = const { RefCell::new(None) };
+}
+
+/// Puts `text` on the system clipboard.
+pub fn copy(text: &str) -> Result<()> {
+    CLIPBOARD.with(|cell| {
+        let mut slot = cell.borrow_mut();
+        if slot.is_none() {
+            *slot = Some(Clipboard::new()?);
+        }
+        if let Some(clipboard) = slot.as_mut() {
+            clipboard.set_text(text)?;
+        }
+        Ok(())
+    })
+}
@@ -0,0 +1,52 @@
+use crate::solana::Network;
+use anyhow::Result;
+
+const EXPLORER_URL: &str = "https://explorer.solana.com";
+
+/// Solana Explorer page for a transaction signature.
+pub fn transaction_url(signature: &str, network: &Network) -> String {
+    format!(
+        "{}/tx/{}{}",
+        EXPLORER_URL,
+        signature,
+        cluster_query(network)
+    )
+}
+
+/// Solana Explorer page for an account address.
+pub fn address_url(address: &str, network: &Network) -> String {
+    format!(
+        "{}/address/{}{}",
+        EXPLORER_URL,
+        address,
+        cluster_query(network)
+    )
+}
+
+/// Opens `url` in the default browser.
+pub fn open(url: &str) -> Result<()> {
+    open::that_detached(url)?;
+    Ok(())
+}
+
+fn cluster_query(network: &Network) -> String {
+    match network {
+        Network::Mainnet => String::new(),
+        Network::Devnet => "?cluster=devnet".to_string(),
+        Network::Testnet => "?cluster=testnet".to_string(),
+        Network::Custom(url) => format!("?cluster=custom&customUrl={}", percent_encode(url)),
+    }
+}
+
+/// Encodes everything but unreserved URL characters, enough to pass an RPC URL as a query value.
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
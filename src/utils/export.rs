@@ -0,0 +1,49 @@
+use crate::solana::types::{TransactionStatus, TransactionSummary};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Writes `contents` to `./<stem>.<extension>` and returns the path written.
+pub fn write_export(stem: &str, extension: &str, contents: &[u8]) -> io::Result<PathBuf> {
+    let path = PathBuf::from(format!("{}.{}", stem, extension));
+    fs::write(&path, contents)?;
+    Ok(path)
+}
+
+/// Account history as CSV with a header row. Times are RFC 3339 in UTC and fees are in
+/// SOL; a fee is left blank when it wasn't fetched.
+pub fn transactions_csv(transactions: &[TransactionSummary]) -> String {
+    let mut csv = String::from("signature,slot,timestamp,status,fee_sol,description\n");
+    for txn in transactions {
+        let status = match txn.status {
+            TransactionStatus::Success => "success",
+            TransactionStatus::Failed(_) => "failed",
+        };
+        let fee = if txn.fee > 0 {
+            format!("{}.{:09}", txn.fee / 1_000_000_000, txn.fee % 1_000_000_000)
+        } else {
+            String::new()
+        };
+        let fields = [
+            txn.signature.to_string(),
+            txn.slot.to_string(),
+            txn.timestamp.map(|t| t.to_rfc3339()).unwrap_or_default(),
+            status.to_string(),
+            fee,
+            txn.description.clone(),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quotes a field if it contains a delimiter, quote or line break, doubling its quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,41 @@
+/// Unit used when displaying lamport amounts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BalanceUnit {
+    Sol,
+    Lamports,
+}
+
+impl BalanceUnit {
+    pub fn toggle(&self) -> Self {
+        match self {
+            Self::Sol => Self::Lamports,
+            Self::Lamports => Self::Sol,
+        }
+    }
+}
+
+/// Formats a lamport amount in the unit the user picked with `u`.
+pub fn format_balance(lamports: u64, unit: BalanceUnit) -> String {
+    match unit {
+        BalanceUnit::Sol => format!("{:.9} SOL", lamports as f64 / 1_000_000_000.0),
+        BalanceUnit::Lamports => format!("{} lamports", group_thousands(lamports)),
+    }
+}
+
+/// Formats a signed lamport change with an explicit sign, e.g. `+0.500000000 SOL`.
+pub fn format_balance_delta(delta: i64, unit: BalanceUnit) -> String {
+    let sign = if delta < 0 { "-" } else { "+" };
+    format!("{}{}", sign, format_balance(delta.unsigned_abs(), unit))
+}
+
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
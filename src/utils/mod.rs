@@ -1,2 +1,7 @@
+pub mod clipboard;
+pub mod explorer;
+pub mod export;
+pub mod format;
+pub mod time;
 pub mod validators;
 
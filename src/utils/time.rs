@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+/// How long ago `time` was: `just now`, `5m ago`, `2h ago`, `3d ago`.
+pub fn relative_time(time: DateTime<Utc>) -> String {
+    match (Utc::now() - time).num_seconds().max(0) {
+        s if s < 60 => "just now".to_string(),
+        s if s < 3_600 => format!("{}m ago", s / 60),
+        s if s < 86_400 => format!("{}h ago", s / 3_600),
+        s => format!("{}d ago", s / 86_400),
+    }
+}
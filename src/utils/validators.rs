@@ -2,6 +2,15 @@
 use solana_sdk::{pubkey::Pubkey, signature::Signature};
 use std::str::FromStr;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputType {
+    Transaction,
+    Account,
+    /// Several signatures, separated by commas or newlines
+    Batch,
+    Unknown,
+}
+
 pub fn is_valid_pubkey(input: &str) -> bool {
     Pubkey::from_str(input).is_ok()
 }
@@ -9,3 +18,163 @@ pub fn is_valid_pubkey(input: &str) -> bool {
 pub fn is_valid_signature(input: &str) -> bool {
     Signature::from_str(input).is_ok()
 }
+
+/// The items of a comma- or newline-separated list, e.g. signatures for a batch lookup.
+pub fn batch_entries(input: &str) -> Vec<&str> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+/// Whether `input` is a transaction signature, an account address, or a list of
+/// signatures. Both parsers are tried whatever the length, since base58 strings of either
+/// kind vary in length with their leading zero bytes.
+pub fn classify_input(input: &str) -> InputType {
+    let trimmed = input.trim();
+    let entries = batch_entries(trimmed);
+    if entries.len() > 1 {
+        return if entries.iter().all(|entry| is_valid_signature(entry)) {
+            InputType::Batch
+        } else {
+            InputType::Unknown
+        };
+    }
+
+    if is_valid_signature(trimmed) {
+        InputType::Transaction
+    } else if is_valid_pubkey(trimmed) {
+        InputType::Account
+    } else {
+        InputType::Unknown
+    }
+}
+
+/// Whether `input` looks like an RPC endpoint we can send requests to.
+pub fn is_valid_rpc_url(input: &str) -> bool {
+    let rest = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"));
+    rest.is_some_and(|host| !host.is_empty() && !host.contains(char::is_whitespace))
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Short explanation of why `input` is neither a signature nor a pubkey. Lengths are
+/// judged by the decoded bytes (32 for an address, 64 for a signature), not the string.
+pub fn describe_invalid_input(input: &str) -> String {
+    let input = input.trim();
+    let entries = batch_entries(input);
+    if entries.len() > 1 {
+        let Some((i, entry)) = entries
+            .iter()
+            .enumerate()
+            .find(|(_, entry)| !is_valid_signature(entry))
+        else {
+            return "not a valid list of signatures".to_string();
+        };
+        let reason = if is_valid_pubkey(entry) {
+            "an address; only signatures can be looked up together".to_string()
+        } else {
+            describe_invalid_input(entry)
+        };
+        return format!("entry {} of {} is {}", i + 1, entries.len(), reason);
+    }
+
+    if let Some(c) = input.chars().find(|c| !BASE58_ALPHABET.contains(*c)) {
+        return format!("not valid base58: '{}' is not a base58 character", c);
+    }
+
+    let chars = input.chars().count();
+    match bs58::decode(input).into_vec().map(|bytes| bytes.len()) {
+        Ok(len) if len < 32 => format!("wrong length: {} chars, too short for an address", chars),
+        Ok(len) if len != 32 && len < 64 => format!(
+            "wrong length: {} chars, too long for an address, too short for a signature",
+            chars
+        ),
+        Ok(len) if len > 64 => format!("wrong length: {} chars, too long for a signature", chars),
+        Ok(_) => "not a valid signature or address".to_string(),
+        Err(_) => "not valid base58".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address() -> String {
+        Pubkey::new_from_array([7; 32]).to_string()
+    }
+
+    fn signature() -> String {
+        Signature::from([7; 64]).to_string()
+    }
+
+    #[test]
+    fn empty_and_blank_input_is_unknown() {
+        assert_eq!(classify_input(""), InputType::Unknown);
+        assert_eq!(classify_input("   \t\n"), InputType::Unknown);
+    }
+
+    #[test]
+    fn classifies_by_decoded_length() {
+        assert_eq!(classify_input(&address()), InputType::Account);
+        assert_eq!(classify_input(&signature()), InputType::Transaction);
+        assert_eq!(
+            classify_input(&format!("  {}\n", address())),
+            InputType::Account
+        );
+    }
+
+    #[test]
+    fn signatures_of_87_and_88_chars_are_transactions() {
+        let short = Signature::from([7; 64]).to_string();
+        let long = Signature::from([0xff; 64]).to_string();
+        assert_eq!(short.len(), 87);
+        assert_eq!(long.len(), 88);
+        assert_eq!(classify_input(&short), InputType::Transaction);
+        assert_eq!(classify_input(&long), InputType::Transaction);
+    }
+
+    #[test]
+    fn rejects_88_chars_that_decode_to_65_bytes() {
+        // A leading '1' is an extra zero byte in front of a 64-byte value
+        let input = format!("1{}", Signature::from([7; 64]));
+        assert_eq!(input.len(), 88);
+        assert_eq!(classify_input(&input), InputType::Unknown);
+        assert_eq!(
+            describe_invalid_input(&input),
+            "wrong length: 88 chars, too long for a signature"
+        );
+    }
+
+    #[test]
+    fn rejects_non_base58_characters() {
+        let input = address().replacen(|c: char| c != '0', "0", 1);
+        assert_eq!(classify_input(&input), InputType::Unknown);
+        assert_eq!(
+            describe_invalid_input(&input),
+            "not valid base58: '0' is not a base58 character"
+        );
+    }
+
+    #[test]
+    fn classifies_signature_lists_as_batches() {
+        let list = format!("{}, {}", signature(), signature());
+        assert_eq!(classify_input(&list), InputType::Batch);
+        let mixed = format!("{}\n{}", signature(), address());
+        assert_eq!(classify_input(&mixed), InputType::Unknown);
+    }
+
+    #[test]
+    fn rpc_urls_need_an_http_scheme_and_host() {
+        assert!(is_valid_rpc_url("https://api.devnet.solana.com"));
+        assert!(is_valid_rpc_url("http://localhost:8899"));
+        assert!(!is_valid_rpc_url("localhost:8899"));
+        assert!(!is_valid_rpc_url("api.devnet.solana.com"));
+        assert!(!is_valid_rpc_url("ws://localhost:8900"));
+        assert!(!is_valid_rpc_url("https://"));
+        assert!(!is_valid_rpc_url("https://local host"));
+        assert!(!is_valid_rpc_url(""));
+    }
+}